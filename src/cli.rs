@@ -0,0 +1,416 @@
+use clap::{Arg, ArgAction, ArgMatches, Command};
+use crate::snapcast::{Client, Group, SnapcastClient, SnapcastError, SnapcastStatus, Stream, StreamCommand, Volume};
+use std::fmt;
+
+// Exit codes of the subcommands, clap itself exits with 2 on bad arguments
+pub const EXIT_FAILED: i32 = 1;
+pub const EXIT_USAGE: i32 = 2;
+pub const EXIT_UNREACHABLE: i32 = 3;
+
+// Names `stream control` accepts, mapped to Stream.Control commands. The
+// protocol's own playPause is taken too, next to the kebab-case spelling.
+const STREAM_COMMANDS: [&str; 7] = ["play", "pause", "play-pause", "playPause", "next", "previous", "seek"];
+
+// Mute states `client mute` accepts
+const MUTE_STATES: [&str; 3] = ["on", "off", "toggle"];
+
+#[derive(Debug)]
+pub enum CliError {
+    // Talking to the server failed
+    Snapcast(Box<SnapcastError>),
+    // Nothing matched the id or name given on the command line
+    NotFound { kind: &'static str, name: String },
+    // More than one entity goes by the name given on the command line
+    Ambiguous { kind: &'static str, name: String, ids: Vec<String> },
+    // The arguments make sense to clap but not to the server, e.g. seek without an offset
+    Usage(String),
+}
+
+impl CliError {
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            CliError::Snapcast(e) if e.is_retryable() => EXIT_UNREACHABLE,
+            CliError::Usage(_) => EXIT_USAGE,
+            _ => EXIT_FAILED,
+        }
+    }
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::Snapcast(e) => write!(f, "{}", e),
+            CliError::NotFound { kind, name } => write!(f, "No {} named '{}'", kind, name),
+            CliError::Ambiguous { kind, name, ids } => {
+                write!(f, "More than one {} named '{}', use one of their ids: {}", kind, name, ids.join(", "))
+            }
+            CliError::Usage(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for CliError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CliError::Snapcast(e) => Some(e.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<SnapcastError> for CliError {
+    fn from(error: SnapcastError) -> Self {
+        CliError::Snapcast(Box::new(error))
+    }
+}
+
+// Subcommands that run one request against the server and exit instead of starting the TUI
+pub fn subcommands() -> Vec<Command> {
+    vec![
+        Command::new("status")
+            .about("Prints the server's groups, clients and streams, one per line")
+            .arg(
+                Arg::new("json")
+                    .long("json")
+                    .action(ArgAction::SetTrue)
+                    .help("Prints the whole status as JSON instead"),
+            ),
+        Command::new("client")
+            .about("Changes a client")
+            .subcommand_required(true)
+            .subcommand(
+                Command::new("volume")
+                    .about("Sets a client's volume")
+                    .arg(Arg::new("client").value_name("CLIENT").required(true).help("Client id or name"))
+                    .arg(
+                        Arg::new("percent")
+                            .value_name("PERCENT")
+                            .required(true)
+                            .value_parser(clap::value_parser!(u32).range(0..=100)),
+                    ),
+            )
+            .subcommand(
+                Command::new("mute")
+                    .about("Mutes or unmutes a client")
+                    .arg(Arg::new("client").value_name("CLIENT").required(true).help("Client id or name"))
+                    .arg(
+                        Arg::new("state")
+                            .value_name("STATE")
+                            .value_parser(MUTE_STATES)
+                            .default_value("on"),
+                    ),
+            ),
+        Command::new("group")
+            .about("Changes a group")
+            .subcommand_required(true)
+            .subcommand(
+                Command::new("stream")
+                    .about("Sets the stream a group plays")
+                    .arg(group_arg())
+                    .arg(Arg::new("stream").value_name("STREAM").required(true).help("Stream id or name")),
+            )
+            .subcommand(
+                Command::new("clients")
+                    .about("Sets the clients of a group, or prints them when none are given")
+                    .arg(group_arg())
+                    .arg(
+                        Arg::new("clients")
+                            .value_name("CLIENT")
+                            .num_args(0..)
+                            .help("Client ids or names, clients missing from the list get a group of their own"),
+                    ),
+            ),
+        Command::new("stream")
+            .about("Controls a stream")
+            .subcommand_required(true)
+            .subcommand(
+                Command::new("control")
+                    .about("Sends a playback command to a stream")
+                    .arg(Arg::new("stream").value_name("STREAM").required(true).help("Stream id or name"))
+                    .arg(
+                        Arg::new("command")
+                            .value_name("COMMAND")
+                            .required(true)
+                            .value_parser(STREAM_COMMANDS),
+                    )
+                    .arg(
+                        Arg::new("seconds")
+                            .value_name("SECONDS")
+                            .allow_negative_numbers(true)
+                            .value_parser(clap::value_parser!(f64))
+                            .help("How far to seek, negative to go back"),
+                    ),
+            ),
+    ]
+}
+
+fn group_arg() -> Arg {
+    Arg::new("group")
+        .value_name("GROUP")
+        .required(true)
+        .help("Group id or name, or the name of one of its clients")
+}
+
+// Run the subcommand `name` against the server at `url`
+pub async fn run(url: &str, name: &str, matches: &ArgMatches) -> Result<(), CliError> {
+    let mut client = SnapcastClient::new(url.to_string());
+    client.fetch_status().await?;
+    let Some(status) = client.status.clone() else {
+        return Err(SnapcastError::Invalid("No status available".to_string()).into());
+    };
+
+    match (name, matches.subcommand()) {
+        ("status", _) => {
+            if matches.get_flag("json") {
+                let json = serde_json::to_string_pretty(&status).map_err(SnapcastError::Schema)?;
+                println!("{}", json);
+            } else {
+                print_status(&status);
+            }
+        }
+        ("client", Some(("volume", matches))) => {
+            let target = find_client(&status, arg(matches, "client"))?;
+            let percent = *matches.get_one::<u32>("percent").expect("percent is required");
+            let volume = Volume { percent, ..target.config.volume.clone() };
+            client.set_client_volume(&target.id, volume).await?;
+        }
+        ("client", Some(("mute", matches))) => {
+            let target = find_client(&status, arg(matches, "client"))?;
+            let current = &target.config.volume;
+            let muted = match arg(matches, "state") {
+                "on" => true,
+                "off" => false,
+                _ => !current.muted,
+            };
+            let volume = Volume { muted, ..current.clone() };
+            client.set_client_volume(&target.id, volume).await?;
+        }
+        ("group", Some(("stream", matches))) => {
+            let group = find_group(&status, arg(matches, "group"))?;
+            let stream = find_stream(&status, arg(matches, "stream"))?;
+            client.set_group_stream(&group.id, &stream.id).await?;
+        }
+        ("group", Some(("clients", matches))) => {
+            let group = find_group(&status, arg(matches, "group"))?;
+            let names: Vec<&String> = matches.get_many::<String>("clients").into_iter().flatten().collect();
+            if names.is_empty() {
+                for member in &group.clients {
+                    println!("{}", client_line(group, member));
+                }
+                return Ok(());
+            }
+
+            let ids = names.iter()
+                .map(|name| find_client(&status, name).map(|member| member.id.clone()))
+                .collect::<Result<Vec<_>, _>>()?;
+            client.set_group_clients(&group.id, ids).await?;
+        }
+        ("stream", Some(("control", matches))) => {
+            let stream = find_stream(&status, arg(matches, "stream"))?;
+            let seconds = matches.get_one::<f64>("seconds").copied();
+            let command = match (arg(matches, "command"), seconds) {
+                ("play", None) => StreamCommand::Play,
+                ("pause", None) => StreamCommand::Pause,
+                ("play-pause" | "playPause", None) => StreamCommand::PlayPause,
+                ("next", None) => StreamCommand::Next,
+                ("previous", None) => StreamCommand::Previous,
+                ("seek", Some(seconds)) => StreamCommand::Seek(seconds),
+                ("seek", None) => return Err(CliError::Usage("seek needs the number of seconds to skip".to_string())),
+                (command, Some(_)) => return Err(CliError::Usage(format!("{} doesn't take a number of seconds", command))),
+                (command, None) => return Err(CliError::Usage(format!("Unknown stream command {}", command))),
+            };
+
+            if !command.is_supported(&stream.properties) {
+                let message = format!("Stream {} does not support {}", stream.id, command.name());
+                return Err(SnapcastError::Invalid(message).into());
+            }
+            client.control_stream(&stream.id, command).await?;
+        }
+        (name, _) => return Err(CliError::Usage(format!("Unknown command {}", name))),
+    }
+
+    Ok(())
+}
+
+fn arg<'a>(matches: &'a ArgMatches, id: &str) -> &'a str {
+    matches.get_one::<String>(id).map(String::as_str).unwrap_or_default()
+}
+
+// One tab separated line per entity, the first column says what it is:
+//
+//   server  <name>  <version>  <host>
+//   group   <id>  <name>  <stream id>  muted|unmuted
+//   client  <id>  <name>  <group id>  <volume>  muted|unmuted  connected|disconnected
+//   stream  <id>  <name>  <status>  <title - artist>
+fn print_status(status: &SnapcastStatus) {
+    let server = &status.server.server;
+    println!(
+        "server\t{}\t{}\t{}",
+        server.snapserver.name, server.snapserver.version, server.host.name
+    );
+
+    for group in &status.server.groups {
+        println!(
+            "group\t{}\t{}\t{}\t{}",
+            group.id,
+            group.name,
+            group.stream_id,
+            if group.muted { "muted" } else { "unmuted" }
+        );
+    }
+    for group in &status.server.groups {
+        for client in &group.clients {
+            println!("{}", client_line(group, client));
+        }
+    }
+    for stream in &status.server.streams {
+        println!(
+            "stream\t{}\t{}\t{}\t{}",
+            stream.id,
+            stream.display_name(),
+            stream.status,
+            stream.now_playing_summary().unwrap_or_default()
+        );
+    }
+}
+
+fn client_line(group: &Group, client: &Client) -> String {
+    let volume = &client.config.volume;
+    format!(
+        "client\t{}\t{}\t{}\t{}\t{}\t{}",
+        client.id,
+        client.display_name(),
+        group.id,
+        volume.percent,
+        if volume.muted { "muted" } else { "unmuted" },
+        if client.connected { "connected" } else { "disconnected" }
+    )
+}
+
+// Match an id exactly, or a name ignoring case when no id matches
+fn find_one<'a, T>(
+    kind: &'static str,
+    name: &str,
+    candidates: impl Iterator<Item = &'a T> + Clone,
+    id: impl Fn(&T) -> &str,
+    names: impl Fn(&T) -> bool,
+) -> Result<&'a T, CliError> {
+    if let Some(found) = candidates.clone().find(|candidate| id(candidate) == name) {
+        return Ok(found);
+    }
+
+    let matches: Vec<&T> = candidates.filter(|candidate| names(candidate)).collect();
+    match matches.as_slice() {
+        [found] => Ok(found),
+        [] => Err(CliError::NotFound { kind, name: name.to_string() }),
+        _ => Err(CliError::Ambiguous {
+            kind,
+            name: name.to_string(),
+            ids: matches.iter().map(|candidate| id(candidate).to_string()).collect(),
+        }),
+    }
+}
+
+fn find_client<'a>(status: &'a SnapcastStatus, name: &str) -> Result<&'a Client, CliError> {
+    find_one(
+        "client",
+        name,
+        status.server.groups.iter().flat_map(|group| group.clients.iter()),
+        |client| &client.id,
+        |client| client.display_name().eq_ignore_ascii_case(name),
+    )
+}
+
+// Unnamed groups can also be found by one of their members
+fn find_group<'a>(status: &'a SnapcastStatus, name: &str) -> Result<&'a Group, CliError> {
+    let groups = status.server.groups.iter();
+    match find_one("group", name, groups.clone(), |group| &group.id, |group| group.name.eq_ignore_ascii_case(name)) {
+        Err(CliError::NotFound { .. }) => find_one(
+            "group",
+            name,
+            groups,
+            |group| &group.id,
+            |group| group.clients.iter().any(|client| client.display_name().eq_ignore_ascii_case(name)),
+        ),
+        result => result,
+    }
+}
+
+fn find_stream<'a>(status: &'a SnapcastStatus, name: &str) -> Result<&'a Stream, CliError> {
+    find_one(
+        "stream",
+        name,
+        status.server.streams.iter(),
+        |stream| &stream.id,
+        |stream| stream.display_name().eq_ignore_ascii_case(name),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STATUS: &str = include_str!("../tests/fixtures/status-0.26.json");
+    const KITCHEN: &str = "dc:a6:32:0f:11:2b";
+    const UNNAMED_GROUP: &str = "1c2b3a4d-5e6f-4a7b-8c9d-0e1f2a3b4c5d";
+
+    fn status() -> SnapcastStatus {
+        serde_json::from_str(STATUS).expect("fixture should parse")
+    }
+
+    #[test]
+    fn finds_by_id() {
+        let status = status();
+
+        assert_eq!(find_client(&status, "desktop#2").unwrap().id, "desktop#2");
+        assert_eq!(find_group(&status, UNNAMED_GROUP).unwrap().id, UNNAMED_GROUP);
+        assert_eq!(find_stream(&status, "Mixed").unwrap().id, "Mixed");
+    }
+
+    #[test]
+    fn finds_by_name_ignoring_case() {
+        let status = status();
+
+        assert_eq!(find_client(&status, "KITCHEN").unwrap().id, KITCHEN);
+        // Unnamed clients go by their hostname
+        assert_eq!(find_client(&status, "Desktop").unwrap().id, "desktop#2");
+        assert_eq!(find_group(&status, "downstairs").unwrap().name, "Downstairs");
+        assert_eq!(find_stream(&status, "spotify").unwrap().id, "Spotify");
+    }
+
+    #[test]
+    fn finds_a_group_by_one_of_its_clients() {
+        let status = status();
+
+        assert_eq!(find_group(&status, "desktop").unwrap().id, UNNAMED_GROUP);
+        assert_eq!(find_group(&status, "kitchen").unwrap().name, "Downstairs");
+    }
+
+    #[test]
+    fn reports_names_nothing_or_several_go_by() {
+        let mut status = status();
+        assert!(matches!(find_client(&status, "garage"), Err(CliError::NotFound { kind: "client", .. })));
+
+        status.server.groups[1].clients[0].config.name = "Kitchen".to_string();
+        match find_client(&status, "kitchen") {
+            Err(CliError::Ambiguous { kind, ids, .. }) => {
+                assert_eq!(kind, "client");
+                assert_eq!(ids, vec![KITCHEN.to_string(), "desktop#2".to_string()]);
+            }
+            other => panic!("expected an ambiguous match, got {:?}", other.map(|client| &client.id)),
+        }
+        // The id still picks one of them
+        assert_eq!(find_client(&status, "desktop#2").unwrap().id, "desktop#2");
+    }
+
+    #[test]
+    fn exit_codes_tell_scripts_what_went_wrong() {
+        assert_eq!(CliError::Usage("seek needs the number of seconds to skip".to_string()).exit_code(), EXIT_USAGE);
+        assert_eq!(CliError::from(SnapcastError::Timeout { method: "Server.GetStatus".to_string() }).exit_code(), EXIT_UNREACHABLE);
+        assert_eq!(CliError::from(SnapcastError::Closed).exit_code(), EXIT_UNREACHABLE);
+        assert_eq!(CliError::NotFound { kind: "client", name: "garage".to_string() }.exit_code(), EXIT_FAILED);
+        assert_eq!(CliError::from(SnapcastError::Invalid("No status available".to_string())).exit_code(), EXIT_FAILED);
+
+        assert_eq!((EXIT_FAILED, EXIT_USAGE, EXIT_UNREACHABLE), (1, 2, 3));
+    }
+}
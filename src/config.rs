@@ -0,0 +1,319 @@
+use serde::Deserialize;
+use std::{
+    collections::BTreeMap,
+    env,
+    fmt,
+    fs,
+    io,
+    path::PathBuf,
+};
+
+pub const DEFAULT_HOST: &str = "127.0.0.1";
+pub const DEFAULT_PORT: u16 = 1780;
+pub const DEFAULT_SCHEME: &str = "ws";
+pub const DEFAULT_PATH: &str = "/jsonrpc";
+
+// Schemes the WebSocket client can connect with, it's built without TLS
+pub const SUPPORTED_SCHEMES: [&str; 1] = ["ws"];
+
+// Contents of $XDG_CONFIG_HOME/snap-tui/config.toml, e.g.
+//
+//   default_profile = "house"
+//
+//   [profiles.house]
+//   host = "nas.local"
+//   label = "House"
+//
+//   [profiles.office]
+//   host = "10.0.4.2"
+//   port = 1788
+//   path = "/snapcast/jsonrpc"
+#[derive(Debug, Default, Deserialize)]
+pub struct Config {
+    // Profile used when --profile isn't given
+    pub default_profile: Option<String>,
+    #[serde(default)]
+    pub profiles: BTreeMap<String, Profile>,
+}
+
+// A named snapserver, any field left out falls back to the default
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Profile {
+    pub host: Option<String>,
+    pub port: Option<u16>,
+    pub scheme: Option<String>,
+    pub path: Option<String>,
+    pub label: Option<String>,
+}
+
+// Snapserver to connect to, after applying CLI, env, config and defaults
+#[derive(Debug, Clone)]
+pub struct Server {
+    // Profile it came from, if any
+    pub profile: Option<String>,
+    pub label: String,
+    pub url: String,
+}
+
+// Host and port given outside the config file, on the command line or in the environment
+#[derive(Debug, Clone, Default)]
+pub struct Endpoint {
+    pub host: Option<String>,
+    pub port: Option<u16>,
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Read { path: PathBuf, source: io::Error },
+    Parse { path: PathBuf, source: toml::de::Error },
+    UnknownProfile { name: String, known: Vec<String> },
+    UnsupportedScheme { profile: String, scheme: String },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Read { path, source } => write!(f, "Can't read {}: {}", path.display(), source),
+            ConfigError::Parse { path, source } => write!(f, "Invalid config {}: {}", path.display(), source),
+            ConfigError::UnknownProfile { name, known } if known.is_empty() => {
+                write!(f, "Unknown profile '{}', no profiles are configured", name)
+            }
+            ConfigError::UnknownProfile { name, known } => {
+                write!(f, "Unknown profile '{}', known profiles: {}", name, known.join(", "))
+            }
+            ConfigError::UnsupportedScheme { profile, scheme } => write!(
+                f,
+                "Profile '{}' uses scheme '{}', supported schemes: {}",
+                profile,
+                scheme,
+                SUPPORTED_SCHEMES.join(", ")
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ConfigError::Read { source, .. } => Some(source),
+            ConfigError::Parse { source, .. } => Some(source),
+            ConfigError::UnknownProfile { .. } | ConfigError::UnsupportedScheme { .. } => None,
+        }
+    }
+}
+
+// $XDG_CONFIG_HOME/snap-tui/config.toml, or ~/.config/snap-tui/config.toml
+pub fn config_path() -> Option<PathBuf> {
+    let base = env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(base.join("snap-tui").join("config.toml"))
+}
+
+impl Config {
+    // Read the config file, a missing file is the same as an empty one
+    pub fn load() -> Result<Self, ConfigError> {
+        let Some(path) = config_path() else {
+            return Ok(Config::default());
+        };
+
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Config::default()),
+            Err(source) => return Err(ConfigError::Read { path, source }),
+        };
+        let config: Config = toml::from_str(&text).map_err(|source| ConfigError::Parse { path, source })?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    // Catch profiles that could never connect while the config is loaded,
+    // rather than on every retry
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        for (name, profile) in &self.profiles {
+            if let Some(scheme) = &profile.scheme
+                && !SUPPORTED_SCHEMES.contains(&scheme.as_str())
+            {
+                return Err(ConfigError::UnsupportedScheme { profile: name.clone(), scheme: scheme.clone() });
+            }
+        }
+        Ok(())
+    }
+
+    // Profile asked for on the command line, or the configured default
+    pub fn profile(&self, name: Option<&str>) -> Result<Option<(&str, &Profile)>, ConfigError> {
+        let Some(name) = name.or(self.default_profile.as_deref()) else {
+            return Ok(None);
+        };
+
+        match self.profiles.get_key_value(name) {
+            Some((name, profile)) => Ok(Some((name.as_str(), profile))),
+            None => Err(ConfigError::UnknownProfile {
+                name: name.to_string(),
+                known: self.profiles.keys().cloned().collect(),
+            }),
+        }
+    }
+
+    // Every configured profile as a server, in name order
+    pub fn servers(&self) -> Vec<Server> {
+        self.profiles.iter()
+            .map(|(name, profile)| profile.server(Some(name)))
+            .collect()
+    }
+
+    // The server to connect to first, followed by the other profiles for the
+    // server switcher. Settings are taken from the command line, then a profile
+    // picked with --profile, then the environment, then the default profile,
+    // then the defaults. The environment doesn't apply to a --profile choice.
+    pub fn resolve_servers(&self, profile: Option<&str>, cli: Endpoint, env: Endpoint) -> Result<Vec<Server>, ConfigError> {
+        let explicit = profile.is_some();
+        let (name, base) = match self.profile(profile)? {
+            Some((name, profile)) => (Some(name), profile.clone()),
+            None => (None, Profile::default()),
+        };
+
+        let env = if explicit { Endpoint::default() } else { env };
+        let host = cli.host.or(env.host).or_else(|| base.host.clone());
+        let port = cli.port.or(env.port).or(base.port);
+
+        // An overridden profile is a different server, so it gets a host:port
+        // label rather than one the profile itself shows up with in the list
+        let overridden = host != base.host || port != base.port;
+        let chosen = Profile {
+            host,
+            port,
+            label: if overridden { None } else { base.label.clone() },
+            ..base
+        };
+
+        let mut servers = vec![chosen.server(if overridden { None } else { name })];
+        for server in self.servers() {
+            if !servers.iter().any(|known| known.url == server.url) {
+                servers.push(server);
+            }
+        }
+        Ok(servers)
+    }
+}
+
+impl Profile {
+    pub fn server(&self, name: Option<&str>) -> Server {
+        let host = self.host.as_deref().unwrap_or(DEFAULT_HOST);
+        let port = self.port.unwrap_or(DEFAULT_PORT);
+        let scheme = self.scheme.as_deref().unwrap_or(DEFAULT_SCHEME);
+        let path = self.path.as_deref().unwrap_or(DEFAULT_PATH);
+
+        Server {
+            profile: name.map(str::to_string),
+            label: self.label.clone()
+                .or_else(|| name.map(str::to_string))
+                .unwrap_or_else(|| format!("{}:{}", host, port)),
+            url: format!("{}://{}:{}/{}", scheme, host, port, path.trim_start_matches('/')),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: &str = r#"
+        default_profile = "house"
+
+        [profiles.house]
+        host = "nas.local"
+        label = "House"
+
+        [profiles.office]
+        host = "10.0.4.2"
+        port = 1788
+        path = "/snapcast/jsonrpc"
+    "#;
+
+    fn config() -> Config {
+        toml::from_str(CONFIG).expect("config should parse")
+    }
+
+    fn endpoint(host: Option<&str>, port: Option<u16>) -> Endpoint {
+        Endpoint { host: host.map(str::to_string), port }
+    }
+
+    #[test]
+    fn falls_back_to_the_defaults() {
+        let servers = Config::default().resolve_servers(None, Endpoint::default(), Endpoint::default()).unwrap();
+
+        assert_eq!(servers.len(), 1);
+        assert_eq!(servers[0].url, "ws://127.0.0.1:1780/jsonrpc");
+        assert_eq!(servers[0].label, "127.0.0.1:1780");
+    }
+
+    #[test]
+    fn uses_the_default_profile_over_the_defaults() {
+        let servers = config().resolve_servers(None, Endpoint::default(), Endpoint::default()).unwrap();
+
+        assert_eq!(servers[0].url, "ws://nas.local:1780/jsonrpc");
+        assert_eq!(servers[0].label, "House");
+        assert_eq!(servers[0].profile.as_deref(), Some("house"));
+        assert_eq!(servers.len(), 2);
+    }
+
+    #[test]
+    fn prefers_the_environment_over_the_default_profile() {
+        let servers = config().resolve_servers(None, Endpoint::default(), endpoint(Some("env.local"), None)).unwrap();
+
+        assert_eq!(servers[0].url, "ws://env.local:1780/jsonrpc");
+        assert_eq!(servers[0].label, "env.local:1780");
+        assert!(servers[0].profile.is_none());
+        // The default profile is still offered, under its own label
+        assert!(servers.iter().any(|server| server.label == "House" && server.url == "ws://nas.local:1780/jsonrpc"));
+    }
+
+    #[test]
+    fn prefers_an_explicit_profile_over_the_environment() {
+        let env = endpoint(Some("env.local"), Some(1111));
+        let servers = config().resolve_servers(Some("office"), Endpoint::default(), env).unwrap();
+
+        assert_eq!(servers[0].url, "ws://10.0.4.2:1788/snapcast/jsonrpc");
+        assert_eq!(servers[0].label, "office");
+    }
+
+    #[test]
+    fn prefers_the_command_line_over_everything() {
+        let cli = endpoint(Some("cli.local"), Some(1800));
+        let env = endpoint(Some("env.local"), Some(1111));
+        let servers = config().resolve_servers(Some("office"), cli, env).unwrap();
+
+        assert_eq!(servers[0].url, "ws://cli.local:1800/snapcast/jsonrpc");
+        assert_eq!(servers[0].label, "cli.local:1800");
+
+        let mut labels: Vec<&str> = servers.iter().map(|server| server.label.as_str()).collect();
+        labels.sort();
+        labels.dedup();
+        assert_eq!(labels.len(), servers.len());
+    }
+
+    #[test]
+    fn rejects_an_unknown_profile() {
+        let result = config().resolve_servers(Some("garage"), Endpoint::default(), Endpoint::default());
+        assert!(matches!(result, Err(ConfigError::UnknownProfile { .. })));
+    }
+
+    #[test]
+    fn rejects_schemes_the_client_cant_connect_with() {
+        assert!(config().validate().is_ok());
+
+        for scheme in ["wss", "http"] {
+            let text = format!("[profiles.office]\nhost = \"10.0.4.2\"\nscheme = \"{}\"\n", scheme);
+            let config: Config = toml::from_str(&text).expect("config should parse");
+            match config.validate() {
+                Err(ConfigError::UnsupportedScheme { profile, scheme: found }) => {
+                    assert_eq!(profile, "office");
+                    assert_eq!(found, scheme);
+                }
+                other => panic!("expected an unsupported scheme error, got {:?}", other),
+            }
+        }
+    }
+}
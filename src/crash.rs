@@ -0,0 +1,55 @@
+use crossterm::{
+    execute,
+    terminal::{disable_raw_mode, LeaveAlternateScreen},
+};
+use std::{
+    backtrace::Backtrace,
+    env,
+    fs,
+    io::{self, Write},
+    panic::{self, PanicHookInfo},
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+// Put the terminal back into a usable state before a panic is reported,
+// then exit the process whichever thread panicked
+pub fn install_panic_hook() {
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        restore_terminal();
+
+        match write_report(info) {
+            Ok(path) => eprintln!("snap-tui crashed, a report was written to {}", path.display()),
+            Err(e) => eprintln!("snap-tui crashed and the crash report couldn't be written: {}", e),
+        }
+
+        default_hook(info);
+
+        // The terminal is restored by now, so a panic in a background task
+        // (the network task, say) can't leave the TUI running on top of it
+        std::process::exit(101);
+    }));
+}
+
+pub fn restore_terminal() {
+    let _ = disable_raw_mode();
+    let _ = execute!(io::stdout(), LeaveAlternateScreen);
+}
+
+// Write the panic message and a backtrace to a file in the temp directory
+fn write_report(info: &PanicHookInfo) -> io::Result<PathBuf> {
+    let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+    let path = env::temp_dir().join(format!("snap-tui-crash-{}.txt", timestamp));
+
+    let mut file = fs::File::create(&path)?;
+    writeln!(file, "snap-tui v{} crashed", env!("CARGO_PKG_VERSION"))?;
+    writeln!(file, "os: {} {}", env::consts::OS, env::consts::ARCH)?;
+    writeln!(file, "time: {}", timestamp)?;
+    writeln!(file)?;
+    writeln!(file, "{}", info)?;
+    writeln!(file)?;
+    writeln!(file, "{}", Backtrace::force_capture())?;
+
+    Ok(path)
+}
@@ -1,160 +1,632 @@
 use crossterm::event::{KeyCode, KeyEvent};
 use crate::App;
-use std::time::Duration;
-use ratatui::{
-    style::{Color, Style},
-    text::Span,
+use crate::popup::{
+    Confirm, ConfirmAction, InputAction, InputPrompt, LatencyEditor, Picker, PickerAction, PickerItem, Popup, StreamField,
+    StreamForm,
 };
+use crate::network::Request;
+use crate::selection::TreeRow;
+use crate::snapcast::{Client, Group, Stream, StreamCommand, Volume};
+use serde_json::{json, Value};
+
+// Picker value standing for "a new group of its own"
+const NEW_GROUP: &str = "";
+
+// Seconds skipped by the seek keys
+const SEEK_SECONDS: f64 = 10.0;
+
+// Playback rate change per key press
+const RATE_STEP: f64 = 0.1;
+
+// Loop modes in the order the loop key cycles through them
+const LOOP_STATUSES: [&str; 3] = ["none", "track", "playlist"];
+
+pub fn handle_input(app: &mut App, key: KeyEvent) {
+    // An open popup takes every key until it is closed
+    if app.popup.is_some() {
+        handle_popup_input(app, key);
+        return;
+    }
 
-pub async fn handle_input(app: &mut App, key: KeyEvent) -> Result<(), Box<dyn std::error::Error>> {
     match key.code {
         KeyCode::Char('q') => {
             app.should_quit = true;
         }
-        KeyCode::Left => {
-            if app.current_tab > 0 {
-                app.current_tab -= 1;
-                app.selected_item = None;
-            }
+        KeyCode::Left if app.current_tab > 0 => {
+            app.current_tab -= 1;
         }
-        KeyCode::Right => {
-            if app.current_tab < 2 {
-                app.current_tab += 1;
-                app.selected_item = None;
-            }
+        KeyCode::Right if app.current_tab < 4 => {
+            app.current_tab += 1;
         }
         KeyCode::Char('g') => {
             // Switch to Groups tab
             app.current_tab = 0;
-            app.selected_item = None;
         }
         KeyCode::Char('c') => {
             // Switch to Clients tab
             app.current_tab = 1;
-                    app.selected_item = None;
-                }
+        }
         KeyCode::Char('s') => {
             // Switch to Streams tab
             app.current_tab = 2;
-            app.selected_item = None;
-                    }
+        }
+        KeyCode::Char('e') => {
+            // Switch to Server tab, 's' is taken by Streams
+            app.current_tab = 3;
+        }
+        KeyCode::Char('d') => {
+            // Switch to Dashboard tab, starting on the active server
+            app.dashboard_cursor = app.active_server;
+            app.current_tab = 4;
+        }
+        KeyCode::Char('S') => {
+            app.popup = Some(server_picker(app));
+        }
         KeyCode::Up => navigate_up(app),
         KeyCode::Down => navigate_down(app),
         KeyCode::Char('r') => {
             // Force a connection retry
-            app.last_connection_attempt = std::time::Instant::now().checked_sub(Duration::from_secs(60)).unwrap_or_else(|| std::time::Instant::now());
-            if let Err(e) = app.attempt_connection().await {
-                app.error_message = Some(format!("Error refreshing Snapcast status: {}", e));
-            } else {
-                app.error_message = Some(
-                    Span::styled(
-                        "Successfully refreshed Snapcast status",
-                        Style::default().fg(Color::Blue)
-                    ).content.into()
-                );
-            }
-            app.selected_item = None;
+            app.attempt_connection();
         }
-        _ => {}
+        _ => match app.current_tab {
+            0 => handle_groups_input(app, key),
+            1 => handle_clients_input(app, key),
+            2 => handle_streams_input(app, key),
+            4 => handle_dashboard_input(app, key),
+            _ => {}
+        },
     }
-    Ok(())
-        }
-fn navigate_up(app: &mut App) {
-    if let Some(status) = &app.snapcast_client.status {
-        match app.current_tab {
-            0 => { // Groups tab
-                if app.selected_item.is_none() {
-                    app.selected_item = Some(0);
-                } else if let Some(idx) = app.selected_item {
-                    if idx > 0 {
-                        app.selected_item = Some(idx - 1);
-                    }
+}
+
+fn handle_dashboard_input(app: &mut App, key: KeyEvent) {
+    if key.code == KeyCode::Enter {
+        // Drill into the server under the cursor
+        app.switch_server(app.dashboard_cursor);
+        app.current_tab = 0;
+    }
+}
+
+fn handle_popup_input(app: &mut App, key: KeyEvent) {
+    match &mut app.popup {
+        Some(Popup::Input(prompt)) => match key.code {
+            KeyCode::Esc => app.popup = None,
+            KeyCode::Enter => {
+                if let Some(Popup::Input(prompt)) = app.popup.take() {
+                    submit_input(app, prompt);
                 }
             }
-            1 => { // Clients tab
-                let client_count = status.server.groups.iter()
-                    .flat_map(|group| &group.clients)
-                    .count();
-
-                if client_count == 0 {
-                    app.selected_item = None;
-                    return;
+            KeyCode::Backspace => {
+                prompt.value.pop();
+            }
+            KeyCode::Char(c) => prompt.value.push(c),
+            _ => {}
+        },
+        Some(Popup::Picker(picker)) => match key.code {
+            KeyCode::Esc => app.popup = None,
+            KeyCode::Up => picker.select_previous(),
+            KeyCode::Down => picker.select_next(),
+            KeyCode::Enter => {
+                if let Some(Popup::Picker(picker)) = app.popup.take() {
+                    submit_picker(app, picker);
                 }
+            }
+            _ => {}
+        },
+        Some(Popup::Latency(editor)) => {
+            let delta = match key.code {
+                KeyCode::Left | KeyCode::Char('-') => -1,
+                KeyCode::Right | KeyCode::Char('+') | KeyCode::Char('=') => 1,
+                KeyCode::Down => -10,
+                KeyCode::Up => 10,
+                _ => 0,
+            };
 
-                if app.selected_item.is_none() {
-                    app.selected_item = Some(0);
-                } else if let Some(idx) = app.selected_item {
-                    if idx > 0 {
-                        app.selected_item = Some(idx - 1);
+            match key.code {
+                _ if delta != 0 => {
+                    editor.nudge(delta);
+                    let (client_id, latency) = (editor.client_id.clone(), editor.current);
+                    set_client_latency(app, &client_id, latency);
+                }
+                KeyCode::Char('r') | KeyCode::Esc => {
+                    // Go back to the latency the editor was opened with
+                    let changed = editor.current != editor.original;
+                    editor.current = editor.original;
+                    let (client_id, latency) = (editor.client_id.clone(), editor.current);
+                    if key.code == KeyCode::Esc {
+                        app.popup = None;
+                    }
+                    if changed {
+                        set_client_latency(app, &client_id, latency);
                     }
                 }
+                KeyCode::Enter => app.popup = None,
+                _ => {}
             }
-            2 => { // Streams tab
-                if app.selected_item.is_none() {
-                    app.selected_item = Some(0);
-                } else if let Some(idx) = app.selected_item {
-                    if idx > 0 {
-                        app.selected_item = Some(idx - 1);
-                    }
+        }
+        Some(Popup::StreamForm(form)) => match key.code {
+            KeyCode::Esc => app.popup = None,
+            KeyCode::Tab | KeyCode::Down => form.focus_next(),
+            KeyCode::BackTab | KeyCode::Up => form.focus_previous(),
+            KeyCode::Left | KeyCode::Right if form.focused_field() == StreamField::Scheme => {
+                form.cycle_scheme(key.code == KeyCode::Right);
+            }
+            KeyCode::Backspace => {
+                if let Some(text) = form.focused_text_mut() {
+                    text.pop();
                 }
             }
+            KeyCode::Char(c) => {
+                if let Some(text) = form.focused_text_mut() {
+                    text.push(c);
+                }
+            }
+            KeyCode::Enter => match form.to_uri() {
+                // Invalid input keeps the form open with the reason
+                Err(e) => form.error = Some(e),
+                Ok(uri) => {
+                    app.popup = None;
+                    app.network.send(Request::AddStream { uri });
+                }
+            },
             _ => {}
+        },
+        Some(Popup::Confirm(_)) => match key.code {
+            KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => {
+                if let Some(Popup::Confirm(confirm)) = app.popup.take() {
+                    submit_confirm(app, confirm);
+                }
+            }
+            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => app.popup = None,
+            _ => {}
+        },
+        None => {}
+    }
+}
+
+fn submit_confirm(app: &mut App, confirm: Confirm) {
+    match confirm.action {
+        ConfirmAction::RemoveStream { stream_id } => {
+            app.network.send(Request::RemoveStream { id: stream_id });
+        }
+        ConfirmAction::DeleteClients { client_ids } => {
+            app.network.send(Request::DeleteClients { ids: client_ids });
         }
     }
 }
 
-fn navigate_down(app: &mut App) {
-    if let Some(status) = &app.snapcast_client.status {
-        match app.current_tab {
-            0 => { // Groups tab
-                let group_count = status.server.groups.len();
-                if group_count == 0 {
-                    app.selected_item = None;
-                    return;
-                }
+fn set_client_latency(app: &mut App, client_id: &str, latency: u32) {
+    app.network.send(Request::SetClientLatency { id: client_id.to_string(), latency });
+}
 
-                if app.selected_item.is_none() {
-                    app.selected_item = Some(0);
-                } else if let Some(idx) = app.selected_item {
-                    if idx < group_count - 1 {
-                        app.selected_item = Some(idx + 1);
-                    }
+fn submit_input(app: &mut App, prompt: InputPrompt) {
+    match prompt.action {
+        InputAction::ClientVolume { client_id } => {
+            let percent = match prompt.value.trim().parse::<u32>() {
+                Ok(percent) if percent <= 100 => percent,
+                _ => {
+                    app.show_error("Volume must be a number between 0 and 100");
+                    return;
                 }
+            };
+            let Some(client) = find_client(app, &client_id) else {
+                return;
+            };
+            let volume = Volume { percent, ..client.config.volume.clone() };
+            set_client_volume(app, &client_id, volume);
+        }
+        InputAction::GroupVolume { group_id } => {
+            match prompt.value.trim().parse::<u32>() {
+                Ok(percent) if percent <= 100 => set_group_volume(app, &group_id, percent),
+                _ => app.show_error("Volume must be a number between 0 and 100"),
             }
-            1 => { // Clients tab
-                let client_count = status.server.groups.iter()
-                    .flat_map(|group| &group.clients)
-                    .count();
+        }
+        InputAction::PurgeClients => {
+            let Ok(days) = prompt.value.trim().parse::<u64>() else {
+                app.show_error("Days must be a whole number");
+                return;
+            };
+            app.popup = purge_preview(app, days);
+        }
+        InputAction::ClientName { client_id } => {
+            app.network.send(Request::SetClientName { id: client_id, name: prompt.value.trim().to_string() });
+        }
+        InputAction::GroupName { group_id } => {
+            app.network.send(Request::SetGroupName { id: group_id, name: prompt.value.trim().to_string() });
+        }
+    }
+}
 
-                if client_count == 0 {
-                    app.selected_item = None;
-                    return;
-                }
+fn submit_picker(app: &mut App, picker: Picker) {
+    let Some(item) = picker.selected_item() else {
+        return;
+    };
 
-                if app.selected_item.is_none() {
-                    app.selected_item = Some(0);
-                } else if let Some(idx) = app.selected_item {
-                    if idx < client_count - 1 {
-                        app.selected_item = Some(idx + 1);
-                    }
-                }
+    match &picker.action {
+        PickerAction::GroupStream { group_id } => {
+            app.network.send(Request::SetGroupStream { id: group_id.clone(), stream_id: item.value.clone() });
+        }
+        PickerAction::SwitchServer => {
+            if let Ok(index) = item.value.parse() {
+                app.switch_server(index);
             }
-            2 => { // Streams tab
-                let stream_count = status.server.streams.len();
-                if stream_count == 0 {
-                    app.selected_item = None;
-                    return;
-                }
+        }
+        PickerAction::MoveClientFrom => {
+            app.popup = move_client_picker(app, &item.value);
+        }
+        PickerAction::MoveClient { client_id } => {
+            let target = Some(item.value.clone()).filter(|value| value != NEW_GROUP);
+            app.network.send(Request::MoveClient { client_id: client_id.clone(), target });
+        }
+    }
+}
 
-                if app.selected_item.is_none() {
-                    app.selected_item = Some(0);
-                } else if let Some(idx) = app.selected_item {
-                    if idx < stream_count - 1 {
-                        app.selected_item = Some(idx + 1);
-                    }
-                }
-            }
-            _ => {}
+fn handle_groups_input(app: &mut App, key: KeyEvent) {
+    let Some(row) = app.selected_tree_row() else {
+        return;
+    };
+    let group = match row {
+        TreeRow::Group(group) => group,
+        // Clients nested in the tree take the same keys as in the Clients tab
+        TreeRow::Client(..) => {
+            handle_clients_input(app, key);
+            return;
+        }
+    };
+    let group_id = group.id.clone();
+    let muted = group.muted;
+    let volume = group.volume();
+
+    match key.code {
+        KeyCode::Enter => {
+            app.selection.toggle_group(&group_id);
+            return;
         }
+        KeyCode::Char('m') => {
+            app.network.send(Request::SetGroupMute { id: group_id, mute: !muted });
+            return;
+        }
+        KeyCode::Char('+') | KeyCode::Char('=') | KeyCode::Char('-') => {
+            let Some(volume) = volume else {
+                app.show_error("No connected clients in group");
+                return;
+            };
+            let percent = if key.code == KeyCode::Char('-') {
+                volume.saturating_sub(app.volume_step)
+            } else {
+                (volume + app.volume_step).min(100)
+            };
+            set_group_volume(app, &group_id, percent);
+            return;
+        }
+        _ => {}
+    }
+
+    let popup = match key.code {
+        KeyCode::Char('t') => stream_picker(app, group),
+        KeyCode::Char('v') => Some(Popup::Input(InputPrompt::new(
+            "Set group volume (0-100)",
+            volume.map(|volume| volume.to_string()).unwrap_or_default(),
+            InputAction::GroupVolume { group_id },
+        ))),
+        KeyCode::Char('o') => Some(member_picker(group)),
+        KeyCode::Char('n') => Some(Popup::Input(InputPrompt::new(
+            "Rename group",
+            group.name.clone(),
+            InputAction::GroupName { group_id: group.id.clone() },
+        ))),
+        _ => None,
+    };
+    if popup.is_some() {
+        app.popup = popup;
+    }
+}
+
+fn stream_picker(app: &App, group: &Group) -> Option<Popup> {
+    let status = app.network.status.as_ref()?;
+
+    let items = status.server.streams
+        .iter()
+        .map(|stream| PickerItem {
+            label: stream.display_name().to_string(),
+            detail: if stream.id == group.stream_id {
+                format!("{} (current)", stream.status)
+            } else {
+                stream.status.clone()
+            },
+            value: stream.id.clone(),
+        })
+        .collect();
+
+    let picker = Picker::new("Select stream", items, PickerAction::GroupStream { group_id: group.id.clone() })
+        .with_selected_value(&group.stream_id);
+    Some(Popup::Picker(picker))
+}
+
+// Every known server, from the command line and the config file profiles
+fn server_picker(app: &App) -> Popup {
+    let items = app.servers
+        .iter()
+        .enumerate()
+        .map(|(idx, server)| PickerItem {
+            label: server.label.clone(),
+            detail: if idx == app.active_server {
+                format!("{} (active)", server.url)
+            } else {
+                server.url.clone()
+            },
+            value: idx.to_string(),
+        })
+        .collect();
+
+    let picker = Picker::new("Switch server", items, PickerAction::SwitchServer)
+        .with_selected_value(&app.active_server.to_string());
+    Popup::Picker(picker)
+}
+
+fn member_picker(group: &Group) -> Popup {
+    let items = group.clients
+        .iter()
+        .map(|client| PickerItem {
+            label: client.display_name().to_string(),
+            detail: if client.connected { "connected".to_string() } else { "disconnected".to_string() },
+            value: client.id.clone(),
+        })
+        .collect();
+
+    Popup::Picker(Picker::new("Move which client", items, PickerAction::MoveClientFrom))
+}
+
+// Offer every other group, plus a new group, as destination for a client
+fn move_client_picker(app: &App, client_id: &str) -> Option<Popup> {
+    let status = app.network.status.as_ref()?;
+    let source = status.server.groups.iter()
+        .find(|group| group.clients.iter().any(|client| client.id == client_id))?;
+
+    let mut items: Vec<PickerItem> = status.server.groups
+        .iter()
+        .filter(|group| group.id != source.id)
+        .map(|group| PickerItem {
+            label: group.display_name().to_string(),
+            detail: format!("{} client(s)", group.clients.len()),
+            value: group.id.clone(),
+        })
+        .collect();
+    items.push(PickerItem {
+        label: "New group".to_string(),
+        detail: "on its own".to_string(),
+        value: NEW_GROUP.to_string(),
+    });
+
+    let picker = Picker::new("Move client to", items, PickerAction::MoveClient { client_id: client_id.to_string() });
+    Some(Popup::Picker(picker))
+}
+
+// Ask before deleting every client that has been gone for `days`
+fn purge_preview(app: &mut App, days: u64) -> Option<Popup> {
+    let status = app.network.status.as_ref()?;
+    let stale = status.stale_clients_for_days(days);
+
+    if stale.is_empty() {
+        app.show_info(format!("No disconnected clients unseen for {} day(s)", days));
+        return None;
+    }
+
+    let details = stale.iter()
+        .map(|client| format!("{} ({})", client.display_name(), client.id))
+        .collect();
+    let client_ids = stale.iter().map(|client| client.id.clone()).collect();
+
+    let confirm = Confirm::new(
+        format!("Delete {} client(s) not seen for {} day(s)?", stale.len(), days),
+        ConfirmAction::DeleteClients { client_ids },
+    )
+    .with_details(details);
+    Some(Popup::Confirm(confirm))
+}
+
+fn handle_clients_input(app: &mut App, key: KeyEvent) {
+    // Purging looks at every client, not just the selected one
+    if key.code == KeyCode::Char('P') {
+        app.popup = Some(Popup::Input(InputPrompt::new(
+            "Purge clients not seen for how many days?",
+            "30",
+            InputAction::PurgeClients,
+        )));
+        return;
+    }
+
+    let Some(client) = app.selected_client() else {
+        return;
+    };
+    let client_id = client.id.clone();
+    let client_name = client.config.name.clone();
+    let client_name_or_id = client.display_name().to_string();
+    let latency = client.config.latency;
+    let volume = client.config.volume.clone();
+
+    match key.code {
+        KeyCode::Char('+') | KeyCode::Char('=') => {
+            let percent = (volume.percent + app.volume_step).min(100);
+            set_client_volume(app, &client_id, Volume { percent, ..volume });
+        }
+        KeyCode::Char('-') => {
+            let percent = volume.percent.saturating_sub(app.volume_step);
+            set_client_volume(app, &client_id, Volume { percent, ..volume });
+        }
+        KeyCode::Char('m') => {
+            let muted = !volume.muted;
+            set_client_volume(app, &client_id, Volume { muted, ..volume });
+        }
+        KeyCode::Char('v') => {
+            app.popup = Some(Popup::Input(InputPrompt::new(
+                "Set volume (0-100)",
+                volume.percent.to_string(),
+                InputAction::ClientVolume { client_id },
+            )));
+        }
+        KeyCode::Char('o') => {
+            app.popup = move_client_picker(app, &client_id);
+        }
+        KeyCode::Char('l') => {
+            app.popup = Some(Popup::Latency(LatencyEditor::new(client_id, latency)));
+        }
+        KeyCode::Char('x') | KeyCode::Delete => {
+            let confirm = Confirm::new(
+                format!("Delete client {}?", client_name_or_id),
+                ConfirmAction::DeleteClients { client_ids: vec![client_id] },
+            );
+            app.popup = Some(Popup::Confirm(confirm));
+        }
+        KeyCode::Char('n') => {
+            app.popup = Some(Popup::Input(InputPrompt::new(
+                "Rename client",
+                client_name,
+                InputAction::ClientName { client_id },
+            )));
+        }
+        _ => {}
+    }
+}
+
+fn set_group_volume(app: &mut App, group_id: &str, percent: u32) {
+    app.network.send(Request::SetGroupVolume { id: group_id.to_string(), percent });
+}
+
+fn set_client_volume(app: &mut App, client_id: &str, volume: Volume) {
+    app.network.send(Request::SetClientVolume { id: client_id.to_string(), volume });
+}
+
+fn handle_streams_input(app: &mut App, key: KeyEvent) {
+    // Adding a stream doesn't need one to be selected
+    if key.code == KeyCode::Char('a') {
+        app.popup = Some(Popup::StreamForm(StreamForm::default()));
+        return;
+    }
+
+    let Some(stream) = app.selected_stream() else {
+        return;
+    };
+
+    if key.code == KeyCode::Char('x') || key.code == KeyCode::Delete {
+        app.popup = Some(Popup::Confirm(Confirm::new(
+            format!("Remove stream {}?", stream.display_name()),
+            ConfirmAction::RemoveStream { stream_id: stream.id.clone() },
+        )));
+        return;
+    }
+
+    // Stepping from an unknown level would jump straight to 0 or the step size
+    let volume_keys = [KeyCode::Char('+'), KeyCode::Char('='), KeyCode::Char('-')];
+    if volume_keys.contains(&key.code) && stream.properties.volume.is_none() {
+        app.show_error(format!("Stream {} doesn't report its volume", stream.id));
+        return;
+    }
+
+    if let Some((property, value)) = stream_property_change(app, stream, key) {
+        if !stream.properties.can_control {
+            app.show_error(format!("Stream {} can't be controlled", stream.id));
+            return;
+        }
+
+        let stream_id = stream.id.clone();
+        app.network.send(Request::SetStreamProperty { id: stream_id, property, value });
+        return;
+    }
+
+    let command = match key.code {
+        KeyCode::Char(' ') => StreamCommand::PlayPause,
+        KeyCode::Char('p') => StreamCommand::Play,
+        KeyCode::Char('P') => StreamCommand::Pause,
+        KeyCode::Char('>') => StreamCommand::Next,
+        KeyCode::Char('<') => StreamCommand::Previous,
+        KeyCode::Char(']') => StreamCommand::Seek(SEEK_SECONDS),
+        KeyCode::Char('[') => StreamCommand::Seek(-SEEK_SECONDS),
+        _ => return,
+    };
+
+    if !command.is_supported(&stream.properties) {
+        app.show_error(format!("Stream {} does not support {}", stream.id, command.name()));
+        return;
+    }
+
+    let stream_id = stream.id.clone();
+    app.network.send(Request::ControlStream { id: stream_id, command });
+}
+
+// Property and new value a Streams tab key asks for, based on the current properties
+fn stream_property_change(app: &App, stream: &Stream, key: KeyEvent) -> Option<(&'static str, Value)> {
+    let properties = &stream.properties;
+
+    match key.code {
+        KeyCode::Char('l') => {
+            let current = properties.loop_status.as_deref().unwrap_or("none");
+            let idx = LOOP_STATUSES.iter().position(|status| *status == current).unwrap_or(0);
+            Some(("loopStatus", json!(LOOP_STATUSES[(idx + 1) % LOOP_STATUSES.len()])))
+        }
+        KeyCode::Char('h') => Some(("shuffle", json!(!properties.shuffle.unwrap_or(false)))),
+        KeyCode::Char('m') => Some(("mute", json!(!properties.mute.unwrap_or(false)))),
+        KeyCode::Char('+') | KeyCode::Char('=') => {
+            let volume = properties.volume?;
+            Some(("volume", json!((volume + app.volume_step).min(100))))
+        }
+        KeyCode::Char('-') => {
+            let volume = properties.volume?;
+            Some(("volume", json!(volume.saturating_sub(app.volume_step))))
+        }
+        KeyCode::Char('}') | KeyCode::Char('{') => {
+            let rate = properties.rate.unwrap_or(1.0);
+            let delta = if key.code == KeyCode::Char('}') { RATE_STEP } else { -RATE_STEP };
+            // Keep one decimal so repeated steps don't drift
+            let rate = ((rate + delta) * 10.0).round() / 10.0;
+            Some(("rate", json!(rate.max(RATE_STEP))))
+        }
+        _ => None,
+    }
+}
+
+fn find_client<'a>(app: &'a App, client_id: &str) -> Option<&'a Client> {
+    let status = app.network.status.as_ref()?;
+    status.server.groups.iter()
+        .flat_map(|group| group.clients.iter())
+        .find(|client| client.id == client_id)
+}
+
+fn navigate_up(app: &mut App) {
+    if app.current_tab == 4 {
+        app.dashboard_cursor = app.dashboard_cursor.saturating_sub(1);
+        return;
+    }
+    if let Some(status) = &app.network.status {
+        let count = app.selection.tab_ids(status, app.current_tab).len();
+        if count == 0 {
+            app.selection.clear(app.current_tab);
+            return;
+        }
+
+        let idx = match app.selection.index(app.current_tab, status) {
+            Some(idx) => idx.saturating_sub(1),
+            None => 0,
+        };
+        app.selection.select_index(app.current_tab, status, idx);
+    }
+}
+
+fn navigate_down(app: &mut App) {
+    if app.current_tab == 4 {
+        app.dashboard_cursor = (app.dashboard_cursor + 1).min(app.servers.len().saturating_sub(1));
+        return;
+    }
+    if let Some(status) = &app.network.status {
+        let count = app.selection.tab_ids(status, app.current_tab).len();
+        if count == 0 {
+            app.selection.clear(app.current_tab);
+            return;
+        }
+
+        let idx = match app.selection.index(app.current_tab, status) {
+            Some(idx) => (idx + 1).min(count - 1),
+            None => 0,
+        };
+        app.selection.select_index(app.current_tab, status, idx);
     }
 }
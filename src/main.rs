@@ -1,4 +1,11 @@
+pub mod cli;
+pub mod config;
+pub mod crash;
 pub mod input;
+pub mod monitor;
+pub mod network;
+pub mod popup;
+pub mod selection;
 pub mod snapcast;
 pub mod ui;
 
@@ -9,6 +16,7 @@ use crossterm::{
 };
 use ratatui::prelude::*;
 use std::{
+    collections::HashMap,
     env,
     io,
     time::Duration,
@@ -16,46 +24,246 @@ use std::{
 use clap::{Arg, Command};
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
-const DEFAULT_HOST: &str = "127.0.0.1";
-const DEFAULT_PORT: &str = "1780";
+const DEFAULT_VOLUME_STEP: &str = "5";
 const RETRY_INTERVAL: Duration = Duration::from_secs(5);
 
 #[derive(Debug)]
 pub struct App {
     pub should_quit: bool,
-    pub snapcast_client: snapcast::SnapcastClient,
-    pub selected_item: Option<usize>,
+    pub network: network::Network,
+    // Selected group, client and stream, one per tab
+    pub selection: selection::Selection,
     pub current_tab: usize,
     pub connection_error: Option<String>,
+    // Whether the last connection failure is worth retrying on a timer
+    pub retry_connection: bool,
     pub last_connection_attempt: std::time::Instant,
     pub error_message: Option<String>,
     pub info_message: Option<String>, // Add this line for non-error messages
+    pub message_time: std::time::Instant,
+    pub popup: Option<popup::Popup>,
+    pub volume_step: u32,
+    // Servers to switch between, from the command line and the config file
+    pub servers: Vec<config::Server>,
+    pub active_server: usize,
+    // Selection of the servers not shown right now, keyed by URL
+    pub parked_selections: HashMap<String, selection::Selection>,
+    // Sessions to every other server for the dashboard, keyed by URL
+    pub monitors: HashMap<String, monitor::Monitor>,
+    // Server panel under the cursor in the Dashboard tab
+    pub dashboard_cursor: usize,
 }
 
 impl App {
-    pub async fn attempt_connection(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+    pub fn show_error(&mut self, message: impl Into<String>) {
+        self.error_message = Some(message.into());
+        self.info_message = None;
+        self.message_time = std::time::Instant::now();
+    }
+
+    // Report a failed action along with what the user can do about it
+    pub fn show_failure(&mut self, action: &str, error: &snapcast::SnapcastError) {
+        match error.hint() {
+            Some(hint) => self.show_error(format!("{} failed: {} ({})", action, error, hint)),
+            None => self.show_error(format!("{} failed: {}", action, error)),
+        }
+    }
+
+    // Position of the current tab's selection in its list
+    pub fn selected_index(&self) -> Option<usize> {
+        let status = self.network.status.as_ref()?;
+        self.selection.index(self.current_tab, status)
+    }
+
+    // Row under the cursor in the Groups tab tree
+    pub fn selected_tree_row(&self) -> Option<selection::TreeRow<'_>> {
+        let status = self.network.status.as_ref()?;
+        let id = self.selection.id(0)?;
+        self.selection.group_tree(status).into_iter().find(|row| row.id() == id)
+    }
+
+    // Group under the cursor in the Groups tab
+    pub fn selected_group(&self) -> Option<&snapcast::Group> {
+        match self.selected_tree_row()? {
+            selection::TreeRow::Group(group) if self.current_tab == 0 => Some(group),
+            _ => None,
+        }
+    }
+
+    // Client under the cursor, nested in the Groups tab tree or in the Clients tab
+    pub fn selected_client(&self) -> Option<&snapcast::Client> {
+        match self.current_tab {
+            0 => match self.selected_tree_row()? {
+                selection::TreeRow::Client(_, client) => Some(client),
+                selection::TreeRow::Group(_) => None,
+            },
+            1 => {
+                let status = self.network.status.as_ref()?;
+                let id = self.selection.id(1)?;
+                status.server.groups.iter()
+                    .flat_map(|group| group.clients.iter())
+                    .find(|client| client.id == id)
+            }
+            _ => None,
+        }
+    }
+
+    // Stream under the cursor in the Streams tab
+    pub fn selected_stream(&self) -> Option<&snapcast::Stream> {
+        let status = self.network.status.as_ref()?;
+        let id = self.selection.id(2)?;
+        status.server.streams.iter().find(|stream| stream.id == id)
+    }
+
+    pub fn show_info(&mut self, message: impl Into<String>) {
+        self.info_message = Some(message.into());
+        self.error_message = None;
+        self.message_time = std::time::Instant::now();
+    }
+
+    pub fn active_server(&self) -> Option<&config::Server> {
+        self.servers.get(self.active_server)
+    }
+
+    // Session and connection error of one of the servers, the active one or
+    // a dashboard monitor
+    pub fn session(&self, index: usize) -> Option<(&network::Network, Option<&str>)> {
+        if index == self.active_server {
+            return Some((&self.network, self.connection_error.as_deref()));
+        }
+        let monitor = self.monitors.get(&self.servers.get(index)?.url)?;
+        Some((&monitor.network, monitor.connection_error.as_deref()))
+    }
+
+    // Request that failed on a background server, the active one shows its own right away
+    pub fn session_failure(&self, index: usize) -> Option<&str> {
+        if index == self.active_server {
+            return None;
+        }
+        self.monitors.get(&self.servers.get(index)?.url)?.failure.as_deref()
+    }
+
+    // Make another server the active one. Its dashboard session takes over and
+    // the current one is kept up in the background, along with its selection.
+    pub fn switch_server(&mut self, index: usize) {
+        if index == self.active_server {
+            return;
+        }
+        let Some(server) = self.servers.get(index).cloned() else {
+            return;
+        };
+
+        let restored = self.parked_selections.remove(&server.url).unwrap_or_default();
+        let parked = std::mem::replace(&mut self.selection, restored);
+        if let Some(previous) = self.active_server() {
+            self.parked_selections.insert(previous.url.clone(), parked);
+        }
+
+        let incoming = self.monitors.remove(&server.url)
+            .unwrap_or_else(|| monitor::Monitor::spawn(server.url.clone()));
+        let outgoing = monitor::Monitor {
+            network: std::mem::replace(&mut self.network, incoming.network),
+            connection_error: std::mem::replace(&mut self.connection_error, incoming.connection_error),
+            retry_connection: std::mem::replace(&mut self.retry_connection, incoming.retry_connection),
+            last_connection_attempt: std::mem::replace(
+                &mut self.last_connection_attempt,
+                incoming.last_connection_attempt,
+            ),
+            failure: None,
+        };
+        self.monitors.insert(outgoing.network.get_url().to_string(), outgoing);
+        self.active_server = index;
+
+        // The status moved on while the selection was parked
+        if let Some(status) = &self.network.status {
+            self.selection.sync(status);
+        }
+        match incoming.failure {
+            Some(failure) => self.show_error(failure),
+            None => self.show_info(format!("Switched to {}", server.label)),
+        }
+    }
+
+    // Ask the network task to (re)connect, the outcome arrives as an event
+    pub fn attempt_connection(&mut self) {
         self.last_connection_attempt = std::time::Instant::now();
-        match self.snapcast_client.fetch_status().await {
-        Ok(_) => {
-                self.connection_error = None;
-    Ok(())
-}
-            Err(e) => {
-                let error_msg = format!("Connection error: {}", e);
-                self.connection_error = Some(error_msg.clone());
-                self.error_message = Some(error_msg);
-                Err(e)
+        self.network.send(network::Request::Connect);
+    }
+
+    // Apply everything the network task reported since the last call
+    pub fn process_network_events(&mut self) {
+        while let Some(event) = self.network.next_event() {
+            match event {
+                // The network has already taken the status, with any pending previews
+                network::Event::Status(_) => {
+                    if let Some(status) = &self.network.status {
+                        self.selection.sync(status);
+                    }
+                }
+                network::Event::Connection(Ok(())) => {
+                    if self.connection_error.take().is_some() {
+                        self.show_info("Connection attempt successful");
+                    } else {
+                        self.show_info("Successfully refreshed Snapcast status");
+                    }
+                }
+                network::Event::Connection(Err(e)) => {
+                    self.connection_error = Some(format!("Connection error: {}", e));
+                    self.retry_connection = e.is_retryable();
+                    self.last_connection_attempt = std::time::Instant::now();
+                    self.show_failure("Connection", &e);
+                }
+                network::Event::Disconnected => {
+                    if self.connection_error.is_none() {
+                        self.connection_error = Some("Connection to server lost".to_string());
+                        self.retry_connection = true;
+                        self.last_connection_attempt = std::time::Instant::now();
+                    }
+                }
+                network::Event::Notifications(_) => {}
+                network::Event::Finished { action, result } => match result {
+                    Ok(Some(message)) => self.show_info(message),
+                    Ok(None) => {}
+                    Err(e) => self.show_failure(&action, &e),
+                },
             }
         }
+
+        // Dashboard sessions only update their own status
+        for monitor in self.monitors.values_mut() {
+            monitor.process_events();
+        }
     }
 }
 
-fn get_snapserver_host() -> String {
-    env::var("SNAPSERVER_HOST").unwrap_or_else(|_| DEFAULT_HOST.to_string())
+fn get_snapserver_host() -> Option<String> {
+    env::var("SNAPSERVER_HOST").ok().filter(|host| !host.is_empty())
 }
 
-fn get_snapserver_port() -> String {
-    env::var("SNAPSERVER_PORT").unwrap_or_else(|_| DEFAULT_PORT.to_string())
+fn get_snapserver_port() -> Result<Option<u16>, String> {
+    match env::var("SNAPSERVER_PORT") {
+        Ok(port) if !port.is_empty() => port.parse()
+            .map(Some)
+            .map_err(|_| format!("SNAPSERVER_PORT must be a port number, got '{}'", port)),
+        _ => Ok(None),
+    }
+}
+
+// Pick the server from the command line, the environment and the config file.
+// The other profiles follow it so the server switcher can offer them.
+fn resolve_servers(matches: &clap::ArgMatches) -> Result<Vec<config::Server>, Box<dyn std::error::Error>> {
+    let cli = config::Endpoint {
+        host: matches.get_one::<String>("host").cloned(),
+        port: matches.get_one::<u16>("port").copied(),
+    };
+    // A bad SNAPSERVER_PORT only matters when --port doesn't replace it
+    let env = config::Endpoint {
+        host: get_snapserver_host(),
+        port: if cli.port.is_some() { None } else { get_snapserver_port()? },
+    };
+
+    let config = config::Config::load()?;
+    Ok(config.resolve_servers(matches.get_one::<String>("profile").map(String::as_str), cli, env)?)
 }
 
 #[tokio::main]
@@ -73,16 +281,33 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             Arg::new("host")
                 .long("host")
                 .value_name("HOST")
-                .help("Sets the Snapcast server host")
-                .default_value(DEFAULT_HOST),
+                .help("Sets the Snapcast server host [env: SNAPSERVER_HOST] [default: 127.0.0.1]")
+                .global(true),
         )
         .arg(
             Arg::new("port")
                 .long("port")
                 .value_name("PORT")
-                .help("Sets the Snapcast server port")
-                .default_value(DEFAULT_PORT),
+                .help("Sets the Snapcast server port [env: SNAPSERVER_PORT] [default: 1780]")
+                .value_parser(clap::value_parser!(u16))
+                .global(true),
         )
+        .arg(
+            Arg::new("profile")
+                .long("profile")
+                .value_name("NAME")
+                .help("Uses a server profile from the config file")
+                .global(true),
+        )
+        .arg(
+            Arg::new("volume-step")
+                .long("volume-step")
+                .value_name("PERCENT")
+                .help("Sets how much the volume keys change a client's volume")
+                .value_parser(clap::value_parser!(u32).range(1..=100))
+                .default_value(DEFAULT_VOLUME_STEP),
+        )
+        .subcommands(cli::subcommands())
         .get_matches();
 
     // Check for version flag
@@ -91,14 +316,25 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         return Ok(());
     }
 
-    // Get host and port with proper precedence
-    let host = matches.get_one::<String>("host")
-        .map(|s| s.clone())
-        .unwrap_or_else(|| get_snapserver_host());
+    let servers = match resolve_servers(&matches) {
+        Ok(servers) => servers,
+        Err(e) => {
+            eprintln!("snap-tui: {}", e);
+            std::process::exit(2);
+        }
+    };
+
+    // Subcommands do their one thing and exit without starting the TUI
+    if let Some((name, sub_matches)) = matches.subcommand() {
+        if let Err(e) = cli::run(&servers[0].url, name, sub_matches).await {
+            eprintln!("snap-tui: {}", e);
+            std::process::exit(e.exit_code());
+        }
+        return Ok(());
+    }
 
-    let port = matches.get_one::<String>("port")
-        .map(|s| s.clone())
-        .unwrap_or_else(|| get_snapserver_port());
+    // Restore the terminal and leave a crash report if anything panics
+    crash::install_panic_hook();
 
     // Setup terminal
     enable_raw_mode()?;
@@ -107,21 +343,35 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
-    // Create app state with configured host and port
-    let server_url = format!("ws://{}:{}/jsonrpc", host, port);
+    // Every other server is watched in the background for the dashboard
+    let monitors = servers.iter()
+        .skip(1)
+        .map(|server| (server.url.clone(), monitor::Monitor::spawn(server.url.clone())))
+        .collect();
+
+    // Create app state with the chosen server
     let mut app = App {
         should_quit: false,
-        snapcast_client: snapcast::SnapcastClient::new(server_url),
-        selected_item: None,
+        network: network::Network::spawn(servers[0].url.clone()),
+        selection: selection::Selection::default(),
         current_tab: 0,
         connection_error: None,
+        retry_connection: true,
         last_connection_attempt: std::time::Instant::now(),
         error_message: None,
         info_message: None, // Add this line for non-error messages
+        message_time: std::time::Instant::now(),
+        popup: None,
+        volume_step: *matches.get_one::<u32>("volume-step").expect("volume-step has a default"),
+        servers,
+        active_server: 0,
+        parked_selections: HashMap::new(),
+        monitors,
+        dashboard_cursor: 0,
     };
 
-    // Initial data fetch attempt
-    app.attempt_connection().await?;
+    // Initial data fetch, the UI keeps running while it connects
+    app.attempt_connection();
 
     // Main loop
     while !app.should_quit {
@@ -129,39 +379,38 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         terminal.draw(|f| ui::ui(f, &app))?;
 
         // Handle input events with timeout to prevent blocking
-        if poll(Duration::from_millis(100))? {
-            if let Event::Key(key) = event::read()? {
-                // Always allow quitting with Ctrl+C or q
-                if key.code == KeyCode::Char('q') || key.code == KeyCode::Char('Q') {
-                    app.should_quit = true;
-                    continue;
-                }
-
-                if let Err(e) = input::handle_input(&mut app, key).await {
-                    eprintln!("Error handling input: {}", e);
-                }
+        if poll(Duration::from_millis(100))?
+            && let Event::Key(key) = event::read()?
+        {
+            // Always allow quitting with Ctrl+C or q, unless q is being typed into a popup
+            if app.popup.is_none() && (key.code == KeyCode::Char('q') || key.code == KeyCode::Char('Q')) {
+                app.should_quit = true;
+                continue;
             }
+
+            input::handle_input(&mut app, key);
         }
 
         // Clear messages after they've been displayed for 3 seconds
-        if app.error_message.is_some() || app.info_message.is_some() {
-            if app.last_connection_attempt.elapsed() >= Duration::from_secs(3) {
-                app.error_message = None;
-                app.info_message = None;
-            }
+        if (app.error_message.is_some() || app.info_message.is_some())
+            && app.message_time.elapsed() >= Duration::from_secs(3)
+        {
+            app.error_message = None;
+            app.info_message = None;
         }
 
+        // Pick up responses, notifications and connection changes from the network tasks
+        app.process_network_events();
+
         // Periodically retry connection if we're disconnected
-        if app.connection_error.is_some() && app.last_connection_attempt.elapsed() >= RETRY_INTERVAL {
-            if let Err(e) = app.attempt_connection().await {
-                app.error_message = Some(format!("Connection attempt failed: {}", e));
-                app.info_message = None;
-            } else {
-                app.info_message = Some("Connection attempt successful".to_string());
-                app.error_message = None;
+        if app.connection_error.is_some()
+            && app.retry_connection
+            && !app.network.is_connecting()
+            && app.last_connection_attempt.elapsed() >= RETRY_INTERVAL
+        {
+            app.attempt_connection();
         }
     }
-    }
 
     // Cleanup terminal
     disable_raw_mode()?;
@@ -0,0 +1,66 @@
+use crate::network::{Event, Network, Request};
+use std::time::Instant;
+
+// Session to a server other than the active one, kept up so the dashboard
+// can show it. Mirrors the connection state `App` keeps for the active server.
+#[derive(Debug)]
+pub struct Monitor {
+    pub network: Network,
+    pub connection_error: Option<String>,
+    pub retry_connection: bool,
+    pub last_connection_attempt: Instant,
+    // Latest request that failed while the server wasn't the active one
+    pub failure: Option<String>,
+}
+
+impl Monitor {
+    // Start a session to `url` and connect right away
+    pub fn spawn(url: String) -> Self {
+        let mut network = Network::spawn(url);
+        network.send(Request::Connect);
+
+        Monitor {
+            network,
+            connection_error: None,
+            retry_connection: true,
+            last_connection_attempt: Instant::now(),
+            failure: None,
+        }
+    }
+
+    // Keep the status current and reconnect on the same timer as the active
+    // server. Nothing pops up, the dashboard panel shows what went wrong.
+    pub fn process_events(&mut self) {
+        while let Some(event) = self.network.next_event() {
+            match event {
+                Event::Connection(Ok(())) => self.connection_error = None,
+                Event::Connection(Err(e)) => {
+                    self.connection_error = Some(format!("Connection error: {}", e));
+                    self.retry_connection = e.is_retryable();
+                    self.last_connection_attempt = Instant::now();
+                }
+                Event::Disconnected => {
+                    if self.connection_error.is_none() {
+                        self.connection_error = Some("Connection to server lost".to_string());
+                        self.retry_connection = true;
+                        self.last_connection_attempt = Instant::now();
+                    }
+                }
+                // Requests still in flight when the user switched away
+                Event::Finished { action, result: Err(e) } => {
+                    self.failure = Some(format!("{} failed: {}", action, e));
+                }
+                Event::Status(_) | Event::Notifications(_) | Event::Finished { .. } => {}
+            }
+        }
+
+        if self.connection_error.is_some()
+            && self.retry_connection
+            && !self.network.is_connecting()
+            && self.last_connection_attempt.elapsed() >= crate::RETRY_INTERVAL
+        {
+            self.last_connection_attempt = Instant::now();
+            self.network.send(Request::Connect);
+        }
+    }
+}
@@ -0,0 +1,407 @@
+use crate::snapcast::{SnapcastClient, SnapcastError, SnapcastStatus, StreamCommand, Volume};
+use serde_json::Value;
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+use tokio::sync::mpsc;
+
+// How often the network task looks for notifications and a lost session while idle
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+// Work the UI hands to the network task
+#[derive(Debug, Clone)]
+pub enum Request {
+    // Connect if needed and fetch the whole status
+    Connect,
+    SetClientVolume { id: String, volume: Volume },
+    SetClientName { id: String, name: String },
+    SetClientLatency { id: String, latency: u32 },
+    DeleteClients { ids: Vec<String> },
+    SetGroupStream { id: String, stream_id: String },
+    SetGroupMute { id: String, mute: bool },
+    SetGroupVolume { id: String, percent: u32 },
+    SetGroupName { id: String, name: String },
+    // None moves the client into a new group of its own
+    MoveClient { client_id: String, target: Option<String> },
+    ControlStream { id: String, command: StreamCommand },
+    SetStreamProperty { id: String, property: &'static str, value: Value },
+    AddStream { uri: String },
+    RemoveStream { id: String },
+}
+
+// What the network task reports back to the UI
+#[derive(Debug)]
+pub enum Event {
+    // The task's cached status changed, by a response or a notification
+    Status(Box<SnapcastStatus>),
+    // Outcome of a Connect request
+    Connection(Result<(), SnapcastError>),
+    // The session went away without being asked to
+    Disconnected,
+    // This many server notifications were applied since the last status
+    Notifications(usize),
+    // Any other request finished, with a message to show when it succeeded
+    Finished { action: String, result: Result<Option<String>, SnapcastError> },
+}
+
+// UI side of the network task: the latest status it sent and the channels to it
+#[derive(Debug)]
+pub struct Network {
+    url: String,
+    // What the UI shows: the task's status with the unanswered requests previewed on top
+    pub status: Option<SnapcastStatus>,
+    // Latest status from the task, only reflecting the requests it has finished
+    confirmed: Option<SnapcastStatus>,
+    requests: mpsc::UnboundedSender<Request>,
+    events: mpsc::UnboundedReceiver<Event>,
+    // Requests sent that haven't been answered yet, oldest first
+    unanswered: VecDeque<Request>,
+    connecting: bool,
+    // When the current session came up, as far as we have seen it stay up
+    pub connected_since: Option<Instant>,
+    pub notification_count: usize,
+    pub last_notification: Option<Instant>,
+}
+
+impl Network {
+    // Start the task that owns the connection to `url`
+    pub fn spawn(url: String) -> Self {
+        let (requests, requests_rx) = mpsc::unbounded_channel();
+        let (events_tx, events) = mpsc::unbounded_channel();
+        tokio::spawn(run(SnapcastClient::new(url.clone()), requests_rx, events_tx));
+
+        Network {
+            url,
+            status: None,
+            confirmed: None,
+            requests,
+            events,
+            unanswered: VecDeque::new(),
+            connecting: false,
+            connected_since: None,
+            notification_count: 0,
+            last_notification: None,
+        }
+    }
+
+    // Queue a request, showing its expected effect on the status right away
+    pub fn send(&mut self, request: Request) {
+        if let Some(status) = &mut self.status {
+            request.preview(status);
+        }
+        if matches!(request, Request::Connect) {
+            self.connecting = true;
+        }
+        if self.requests.send(request.clone()).is_ok() {
+            self.unanswered.push_back(request);
+        }
+    }
+
+    // Next event from the task, without waiting. A status from the task only
+    // becomes `status` once the previews of requests it hasn't finished yet are
+    // put back on top, or a fast second key press would read a stale value.
+    pub fn next_event(&mut self) -> Option<Event> {
+        let event = self.events.try_recv().ok()?;
+        match &event {
+            Event::Status(status) => {
+                self.confirmed = Some(SnapcastStatus::clone(status));
+                self.apply_previews();
+            }
+            Event::Connection(result) => {
+                self.connecting = false;
+                self.unanswered.pop_front();
+                self.apply_previews();
+                match result {
+                    Ok(()) => {
+                        self.connected_since.get_or_insert_with(Instant::now);
+                    }
+                    Err(_) => self.connected_since = None,
+                }
+            }
+            Event::Disconnected => self.connected_since = None,
+            Event::Notifications(count) => {
+                self.notification_count += count;
+                self.last_notification = Some(Instant::now());
+            }
+            Event::Finished { .. } => {
+                self.unanswered.pop_front();
+                self.apply_previews();
+            }
+        }
+        Some(event)
+    }
+
+    fn apply_previews(&mut self) {
+        self.status = self.confirmed.clone();
+        if let Some(status) = &mut self.status {
+            for request in &self.unanswered {
+                request.preview(status);
+            }
+        }
+    }
+
+    pub fn is_busy(&self) -> bool {
+        !self.unanswered.is_empty()
+    }
+
+    pub fn is_connecting(&self) -> bool {
+        self.connecting
+    }
+
+    pub fn get_url(&self) -> &str {
+        &self.url
+    }
+}
+
+impl Request {
+    // What the request does, to prefix its error message with
+    pub fn action(&self) -> String {
+        match self {
+            Request::Connect => "Connection".to_string(),
+            Request::SetClientVolume { .. } => "Setting volume".to_string(),
+            Request::SetClientName { .. } => "Renaming client".to_string(),
+            Request::SetClientLatency { .. } => "Setting latency".to_string(),
+            Request::DeleteClients { .. } => "Deleting clients".to_string(),
+            Request::SetGroupStream { .. } => "Setting stream".to_string(),
+            Request::SetGroupMute { .. } => "Muting group".to_string(),
+            Request::SetGroupVolume { .. } => "Setting group volume".to_string(),
+            Request::SetGroupName { .. } => "Renaming group".to_string(),
+            Request::MoveClient { .. } => "Moving client".to_string(),
+            Request::ControlStream { .. } => "Controlling stream".to_string(),
+            Request::SetStreamProperty { property, .. } => format!("Setting {}", property),
+            Request::AddStream { .. } => "Adding stream".to_string(),
+            Request::RemoveStream { .. } => "Removing stream".to_string(),
+        }
+    }
+
+    // Apply the expected result to the UI's copy of the status until the task confirms it
+    fn preview(&self, status: &mut SnapcastStatus) {
+        match self {
+            Request::SetClientVolume { id, volume } => {
+                if let Some(client) = status.client_mut(id) {
+                    client.config.volume = volume.clone();
+                }
+            }
+            Request::SetClientName { id, name } => {
+                if let Some(client) = status.client_mut(id) {
+                    client.config.name = name.clone();
+                }
+            }
+            Request::SetClientLatency { id, latency } => {
+                if let Some(client) = status.client_mut(id) {
+                    client.config.latency = *latency;
+                }
+            }
+            Request::SetGroupStream { id, stream_id } => {
+                if let Some(group) = status.group_mut(id) {
+                    group.stream_id = stream_id.clone();
+                }
+            }
+            Request::SetGroupMute { id, mute } => {
+                if let Some(group) = status.group_mut(id) {
+                    group.muted = *mute;
+                }
+            }
+            Request::SetGroupVolume { id, percent } => {
+                if let Some(group) = status.group_mut(id) {
+                    for (client_id, percent) in group.scaled_volumes(*percent) {
+                        if let Some(client) = group.clients.iter_mut().find(|client| client.id == client_id) {
+                            client.config.volume.percent = percent;
+                        }
+                    }
+                }
+            }
+            Request::SetGroupName { id, name } => {
+                if let Some(group) = status.group_mut(id) {
+                    group.name = name.clone();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // Carry out the request on the task's client
+    async fn run(self, client: &mut SnapcastClient) -> Result<Option<String>, SnapcastError> {
+        match self {
+            Request::Connect => client.fetch_status().await.map(|_| None),
+            Request::SetClientVolume { id, volume } => client.set_client_volume(&id, volume).await.map(|_| None),
+            Request::SetClientName { id, name } => client.set_client_name(&id, name.trim()).await.map(|_| None),
+            Request::SetClientLatency { id, latency } => client.set_client_latency(&id, latency).await.map(|_| None),
+            Request::DeleteClients { ids } => {
+                for id in &ids {
+                    client.delete_client(id).await?;
+                }
+                Ok(Some(format!("Deleted {} client(s)", ids.len())))
+            }
+            Request::SetGroupStream { id, stream_id } => {
+                let name = client.status.as_ref()
+                    .and_then(|status| status.server.streams.iter().find(|stream| stream.id == stream_id))
+                    .map(|stream| stream.display_name().to_string())
+                    .unwrap_or_else(|| stream_id.clone());
+                client.set_group_stream(&id, &stream_id).await?;
+                Ok(Some(format!("Now playing {}", name)))
+            }
+            Request::SetGroupMute { id, mute } => client.set_group_mute(&id, mute).await.map(|_| None),
+            Request::SetGroupVolume { id, percent } => client.set_group_volume(&id, percent).await.map(|_| None),
+            Request::SetGroupName { id, name } => client.set_group_name(&id, name.trim()).await.map(|_| None),
+            Request::MoveClient { client_id, target } => {
+                let destination = match &target {
+                    Some(target_id) => client.status.as_ref()
+                        .and_then(|status| status.server.groups.iter().find(|group| &group.id == target_id))
+                        .map(|group| group.display_name().to_string())
+                        .unwrap_or_else(|| target_id.clone()),
+                    None => "a new group".to_string(),
+                };
+                client.move_client(&client_id, target.as_deref()).await?;
+                Ok(Some(format!("Moved client to {}", destination)))
+            }
+            Request::ControlStream { id, command } => client.control_stream(&id, command).await.map(|_| None),
+            Request::SetStreamProperty { id, property, value } => {
+                client.set_stream_property(&id, property, value).await.map(|_| None)
+            }
+            Request::AddStream { uri } => {
+                let stream_id = client.add_stream(&uri).await?;
+                Ok(Some(format!("Added stream {}", stream_id)))
+            }
+            Request::RemoveStream { id } => {
+                client.remove_stream(&id).await?;
+                Ok(Some(format!("Removed stream {}", id)))
+            }
+        }
+    }
+}
+
+// Own the client: run requests one at a time and keep the UI's status copy current
+async fn run(
+    mut client: SnapcastClient,
+    mut requests: mpsc::UnboundedReceiver<Request>,
+    events: mpsc::UnboundedSender<Event>,
+) {
+    let mut poll = tokio::time::interval(POLL_INTERVAL);
+    let mut lost = false;
+
+    loop {
+        tokio::select! {
+            // Work queued up by the UI goes before notifications
+            biased;
+
+            request = requests.recv() => {
+                // The UI has quit
+                let Some(request) = request else {
+                    break;
+                };
+
+                let event = match request {
+                    Request::Connect => {
+                        let result = Request::Connect.run(&mut client).await.map(|_| ());
+                        if result.is_ok() {
+                            lost = false;
+                        }
+                        Event::Connection(result)
+                    }
+                    request => {
+                        let action = request.action();
+                        Event::Finished { action, result: request.run(&mut client).await }
+                    }
+                };
+
+                // Successful or not, the status may have changed or been rolled back
+                send_status(&client, &events);
+                let _ = events.send(event);
+            }
+            _ = poll.tick() => {
+                let applied = client.process_notifications();
+                if applied > 0 {
+                    let _ = events.send(Event::Notifications(applied));
+                    send_status(&client, &events);
+                }
+
+                if !lost && client.status.is_some() && !client.is_connected() {
+                    lost = true;
+                    let _ = events.send(Event::Disconnected);
+                }
+            }
+        }
+    }
+}
+
+fn send_status(client: &SnapcastClient, events: &mpsc::UnboundedSender<Event>) {
+    if let Some(status) = &client.status {
+        let _ = events.send(Event::Status(Box::new(status.clone())));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::snapcast::Extra;
+
+    const STATUS: &str = include_str!("../tests/fixtures/status-0.26.json");
+    const KITCHEN: &str = "dc:a6:32:0f:11:2b";
+
+    // A Network whose task side is played by the test
+    fn network() -> (Network, mpsc::UnboundedReceiver<Request>, mpsc::UnboundedSender<Event>) {
+        let (requests, requests_rx) = mpsc::unbounded_channel();
+        let (events_tx, events) = mpsc::unbounded_channel();
+        let status: SnapcastStatus = serde_json::from_str(STATUS).expect("fixture should parse");
+
+        let network = Network {
+            url: String::new(),
+            status: Some(status.clone()),
+            confirmed: Some(status),
+            requests,
+            events,
+            unanswered: VecDeque::new(),
+            connecting: false,
+            connected_since: None,
+            notification_count: 0,
+            last_notification: None,
+        };
+        (network, requests_rx, events_tx)
+    }
+
+    fn set_volume(percent: u32) -> Request {
+        Request::SetClientVolume { id: KITCHEN.to_string(), volume: Volume { percent, muted: false, extra: Extra::new() } }
+    }
+
+    fn kitchen_volume(status: &Option<SnapcastStatus>) -> u32 {
+        let status = status.as_ref().expect("status should be set");
+        status.server.groups.iter()
+            .flat_map(|group| group.clients.iter())
+            .find(|client| client.id == KITCHEN)
+            .expect("kitchen should exist")
+            .config.volume.percent
+    }
+
+    #[test]
+    fn keeps_the_preview_of_a_later_request_over_an_earlier_status() {
+        let (mut network, _requests, events) = network();
+        network.send(set_volume(55));
+        network.send(set_volume(60));
+        assert_eq!(kitchen_volume(&network.status), 60);
+
+        // The task has only done the first request so far
+        let mut status = network.confirmed.clone().unwrap();
+        set_volume(55).preview(&mut status);
+        events.send(Event::Status(Box::new(status))).unwrap();
+        events.send(Event::Finished { action: "Setting volume".to_string(), result: Ok(None) }).unwrap();
+        while network.next_event().is_some() {}
+
+        assert_eq!(kitchen_volume(&network.confirmed), 55);
+        assert_eq!(kitchen_volume(&network.status), 60);
+        assert!(network.is_busy());
+    }
+
+    #[test]
+    fn drops_the_preview_of_a_failed_request() {
+        let (mut network, _requests, events) = network();
+        network.send(set_volume(60));
+
+        events.send(Event::Finished { action: "Setting volume".to_string(), result: Err(SnapcastError::Closed) }).unwrap();
+        while network.next_event().is_some() {}
+
+        assert_eq!(kitchen_volume(&network.status), 48);
+        assert!(!network.is_busy());
+    }
+}
@@ -0,0 +1,380 @@
+// Modal dialogs drawn on top of the current tab
+#[derive(Debug)]
+pub enum Popup {
+    Input(InputPrompt),
+    Picker(Picker),
+    Latency(LatencyEditor),
+    StreamForm(StreamForm),
+    Confirm(Confirm),
+}
+
+// Single line text prompt
+#[derive(Debug)]
+pub struct InputPrompt {
+    pub title: String,
+    pub value: String,
+    pub action: InputAction,
+}
+
+// What to do with the prompt value once it is confirmed
+#[derive(Debug)]
+pub enum InputAction {
+    ClientVolume { client_id: String },
+    ClientName { client_id: String },
+    GroupVolume { group_id: String },
+    GroupName { group_id: String },
+    PurgeClients,
+}
+
+impl InputPrompt {
+    pub fn new(title: impl Into<String>, value: impl Into<String>, action: InputAction) -> Self {
+        InputPrompt {
+            title: title.into(),
+            value: value.into(),
+            action,
+        }
+    }
+}
+
+// List of choices, one of which gets picked with Enter
+#[derive(Debug)]
+pub struct Picker {
+    pub title: String,
+    pub items: Vec<PickerItem>,
+    pub selected: usize,
+    pub action: PickerAction,
+}
+
+#[derive(Debug)]
+pub struct PickerItem {
+    pub label: String,
+    pub detail: String,
+    pub value: String,
+}
+
+// What to do with the value of the picked item
+#[derive(Debug)]
+pub enum PickerAction {
+    GroupStream { group_id: String },
+    // Pick which member of a group to move, then where to
+    MoveClientFrom,
+    MoveClient { client_id: String },
+    // Value is the index into `App.servers`
+    SwitchServer,
+}
+
+impl Picker {
+    pub fn new(title: impl Into<String>, items: Vec<PickerItem>, action: PickerAction) -> Self {
+        Picker {
+            title: title.into(),
+            items,
+            selected: 0,
+            action,
+        }
+    }
+
+    // Start with the cursor on the item holding `value`, if any
+    pub fn with_selected_value(mut self, value: &str) -> Self {
+        if let Some(idx) = self.items.iter().position(|item| item.value == value) {
+            self.selected = idx;
+        }
+        self
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.items.len() {
+            self.selected += 1;
+        }
+    }
+
+    pub fn selected_item(&self) -> Option<&PickerItem> {
+        self.items.get(self.selected)
+    }
+}
+
+// Live latency tuning for one client, remembering where it started
+#[derive(Debug)]
+pub struct LatencyEditor {
+    pub client_id: String,
+    pub original: u32,
+    pub current: u32,
+}
+
+impl LatencyEditor {
+    pub fn new(client_id: impl Into<String>, latency: u32) -> Self {
+        LatencyEditor {
+            client_id: client_id.into(),
+            original: latency,
+            current: latency,
+        }
+    }
+
+    pub fn nudge(&mut self, delta: i32) {
+        self.current = self.current.saturating_add_signed(delta);
+    }
+}
+
+// Yes/no question guarding a destructive action
+#[derive(Debug)]
+pub struct Confirm {
+    pub message: String,
+    // Extra lines listed under the question, e.g. what is about to be removed
+    pub details: Vec<String>,
+    pub action: ConfirmAction,
+}
+
+#[derive(Debug)]
+pub enum ConfirmAction {
+    RemoveStream { stream_id: String },
+    DeleteClients { client_ids: Vec<String> },
+}
+
+impl Confirm {
+    pub fn new(message: impl Into<String>, action: ConfirmAction) -> Self {
+        Confirm {
+            message: message.into(),
+            details: Vec::new(),
+            action,
+        }
+    }
+
+    pub fn with_details(mut self, details: Vec<String>) -> Self {
+        self.details = details;
+        self
+    }
+}
+
+// Stream source types a stream URI can start with
+pub const STREAM_SCHEMES: [&str; 8] = ["pipe", "file", "process", "tcp", "librespot", "airplay", "meta", "alsa"];
+
+// Codecs the server can encode a stream with
+pub const STREAM_CODECS: [&str; 5] = ["flac", "ogg", "opus", "pcm", "null"];
+
+// Fields of the add stream form, in display order
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StreamField {
+    Scheme,
+    Path,
+    Mode,
+    Name,
+    SampleFormat,
+    Codec,
+    ChunkMs,
+}
+
+impl StreamField {
+    pub const ALL: [StreamField; 7] = [
+        StreamField::Scheme,
+        StreamField::Path,
+        StreamField::Mode,
+        StreamField::Name,
+        StreamField::SampleFormat,
+        StreamField::Codec,
+        StreamField::ChunkMs,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            StreamField::Scheme => "Scheme",
+            StreamField::Path => "Path",
+            StreamField::Mode => "Mode",
+            StreamField::Name => "Name",
+            StreamField::SampleFormat => "Sample format",
+            StreamField::Codec => "Codec",
+            StreamField::ChunkMs => "Chunk ms",
+        }
+    }
+
+    pub fn placeholder(&self) -> &'static str {
+        match self {
+            StreamField::Scheme => "",
+            StreamField::Path => "/tmp/snapfifo, or host:port for tcp",
+            StreamField::Mode => "create or read for pipe, server or client for tcp",
+            StreamField::Name => "required",
+            StreamField::SampleFormat => "48000:16:2",
+            StreamField::Codec => "flac, ogg, opus, pcm or null",
+            StreamField::ChunkMs => "20",
+        }
+    }
+}
+
+// Stream URI built field by field, mirroring the uri query parameters
+#[derive(Debug, Default)]
+pub struct StreamForm {
+    pub scheme: usize,
+    pub path: String,
+    pub mode: String,
+    pub name: String,
+    pub sample_format: String,
+    pub codec: String,
+    pub chunk_ms: String,
+    pub focused: usize,
+    pub error: Option<String>,
+}
+
+impl StreamForm {
+    pub fn focused_field(&self) -> StreamField {
+        StreamField::ALL[self.focused]
+    }
+
+    pub fn focus_next(&mut self) {
+        self.focused = (self.focused + 1) % StreamField::ALL.len();
+    }
+
+    pub fn focus_previous(&mut self) {
+        self.focused = (self.focused + StreamField::ALL.len() - 1) % StreamField::ALL.len();
+    }
+
+    pub fn scheme(&self) -> &'static str {
+        STREAM_SCHEMES[self.scheme]
+    }
+
+    // Step through the schemes when the scheme field has focus
+    pub fn cycle_scheme(&mut self, forward: bool) {
+        let len = STREAM_SCHEMES.len();
+        self.scheme = if forward { (self.scheme + 1) % len } else { (self.scheme + len - 1) % len };
+    }
+
+    pub fn value(&self, field: StreamField) -> &str {
+        match field {
+            StreamField::Scheme => self.scheme(),
+            StreamField::Path => &self.path,
+            StreamField::Mode => &self.mode,
+            StreamField::Name => &self.name,
+            StreamField::SampleFormat => &self.sample_format,
+            StreamField::Codec => &self.codec,
+            StreamField::ChunkMs => &self.chunk_ms,
+        }
+    }
+
+    // Text of the focused field, None for the scheme which is picked from a list
+    pub fn focused_text_mut(&mut self) -> Option<&mut String> {
+        match self.focused_field() {
+            StreamField::Scheme => None,
+            StreamField::Path => Some(&mut self.path),
+            StreamField::Mode => Some(&mut self.mode),
+            StreamField::Name => Some(&mut self.name),
+            StreamField::SampleFormat => Some(&mut self.sample_format),
+            StreamField::Codec => Some(&mut self.codec),
+            StreamField::ChunkMs => Some(&mut self.chunk_ms),
+        }
+    }
+
+    // Validate the fields and build the stream URI, e.g. `pipe:///tmp/snapfifo?name=Radio&codec=flac`
+    pub fn to_uri(&self) -> Result<String, String> {
+        let path = self.path.trim();
+        let mode = self.mode.trim();
+        let name = self.name.trim();
+        let sample_format = self.sample_format.trim();
+        let codec = self.codec.trim();
+        let chunk_ms = self.chunk_ms.trim();
+
+        if name.is_empty() {
+            return Err("Name is required".to_string());
+        }
+        if path.is_empty() && self.scheme() != "alsa" {
+            return Err(format!("Path is required for {} streams", self.scheme()));
+        }
+        if self.scheme() == "tcp" && !path.contains(':') {
+            return Err("tcp streams need host:port as path".to_string());
+        }
+        if !mode.is_empty() {
+            let modes = match self.scheme() {
+                "pipe" => ["create", "read"],
+                "tcp" => ["server", "client"],
+                scheme => return Err(format!("Mode doesn't apply to {} streams", scheme)),
+            };
+            if !modes.contains(&mode) {
+                return Err(format!("Mode of {} streams must be {} or {}", self.scheme(), modes[0], modes[1]));
+            }
+        }
+        if !sample_format.is_empty() {
+            let parts: Vec<&str> = sample_format.split(':').collect();
+            if parts.len() != 3 || parts.iter().any(|part| part.parse::<u32>().is_err()) {
+                return Err("Sample format must look like rate:bits:channels, e.g. 48000:16:2".to_string());
+            }
+        }
+        if !codec.is_empty() && !STREAM_CODECS.contains(&codec) {
+            return Err(format!("Codec must be one of {}", STREAM_CODECS.join(", ")));
+        }
+        if !chunk_ms.is_empty() && !chunk_ms.parse::<u32>().is_ok_and(|ms| ms > 0) {
+            return Err("Chunk ms must be a positive number".to_string());
+        }
+
+        let path = if self.scheme() == "tcp" || path.starts_with('/') {
+            path.to_string()
+        } else {
+            format!("/{}", path)
+        };
+
+        let mut uri = format!("{}://{}?name={}", self.scheme(), path, encode_query_value(name));
+        for (key, value) in [("mode", mode), ("sampleformat", sample_format), ("codec", codec), ("chunk_ms", chunk_ms)] {
+            if !value.is_empty() {
+                uri.push_str(&format!("&{}={}", key, encode_query_value(value)));
+            }
+        }
+        Ok(uri)
+    }
+}
+
+// Percent-encode the characters that would break a query parameter
+fn encode_query_value(value: &str) -> String {
+    value.chars()
+        .map(|c| match c {
+            ' ' | '&' | '=' | '?' | '#' | '%' | '+' => format!("%{:02X}", c as u32),
+            _ => c.to_string(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn form(scheme: &str, path: &str, name: &str) -> StreamForm {
+        StreamForm {
+            scheme: STREAM_SCHEMES.iter().position(|candidate| *candidate == scheme).unwrap(),
+            path: path.to_string(),
+            name: name.to_string(),
+            ..StreamForm::default()
+        }
+    }
+
+    #[test]
+    fn builds_uri_with_the_filled_in_parameters() {
+        let mut pipe = form("pipe", "tmp/snapfifo", "Radio One");
+        pipe.mode = "read".to_string();
+        pipe.codec = "flac".to_string();
+        assert_eq!(pipe.to_uri().unwrap(), "pipe:///tmp/snapfifo?name=Radio%20One&mode=read&codec=flac");
+
+        let mut tcp = form("tcp", "0.0.0.0:4953", "Turntable");
+        tcp.mode = "client".to_string();
+        tcp.sample_format = "48000:16:2".to_string();
+        assert_eq!(tcp.to_uri().unwrap(), "tcp://0.0.0.0:4953?name=Turntable&mode=client&sampleformat=48000:16:2");
+    }
+
+    #[test]
+    fn rejects_invalid_fields() {
+        assert!(form("pipe", "/tmp/snapfifo", "").to_uri().is_err());
+        assert!(form("tcp", "localhost", "Turntable").to_uri().is_err());
+
+        let mut tcp = form("tcp", "0.0.0.0:4953", "Turntable");
+        tcp.mode = "read".to_string();
+        assert!(tcp.to_uri().is_err());
+
+        let mut airplay = form("airplay", "/usr/bin/shairport-sync", "AirPlay");
+        airplay.mode = "server".to_string();
+        assert!(airplay.to_uri().is_err());
+
+        let mut pipe = form("pipe", "/tmp/snapfifo", "Radio");
+        pipe.sample_format = "48000:16".to_string();
+        assert!(pipe.to_uri().is_err());
+        pipe.sample_format = String::new();
+        pipe.chunk_ms = "0".to_string();
+        assert!(pipe.to_uri().is_err());
+    }
+}
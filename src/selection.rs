@@ -0,0 +1,242 @@
+use crate::snapcast::{Client, Group, SnapcastStatus};
+use std::{cell::Cell, collections::HashSet};
+
+// Entity under the cursor of one tab. The index is only where it was last seen,
+// used to pick a neighbour once the entity is gone.
+#[derive(Debug, Clone)]
+struct Selected {
+    id: String,
+    index: usize,
+}
+
+// Row of the Groups tab tree: a group, or a member of an expanded group
+#[derive(Debug, Clone, Copy)]
+pub enum TreeRow<'a> {
+    Group(&'a Group),
+    Client(&'a Group, &'a Client),
+}
+
+// Cursor of the Groups, Clients and Streams tabs, remembered by ID so it stays
+// on the same group, client or stream while the lists are rebuilt
+#[derive(Debug, Default)]
+pub struct Selection {
+    tabs: [Option<Selected>; 3],
+    // First visible row of each tab's list, written back while drawing
+    offsets: [Cell<usize>; 3],
+    // Groups folded in the Groups tab tree, every other group shows its clients
+    collapsed: HashSet<String>,
+}
+
+impl Selection {
+    pub fn id(&self, tab: usize) -> Option<&str> {
+        self.tabs.get(tab)?.as_ref().map(|selected| selected.id.as_str())
+    }
+
+    // Position of the selected entity in the tab's list
+    pub fn index(&self, tab: usize, status: &SnapcastStatus) -> Option<usize> {
+        let id = self.id(tab)?;
+        self.tab_ids(status, tab).iter().position(|candidate| *candidate == id)
+    }
+
+    // Select whatever is at `index` in the tab's list
+    pub fn select_index(&mut self, tab: usize, status: &SnapcastStatus, index: usize) {
+        let ids = self.tab_ids(status, tab);
+        let Some(slot) = self.tabs.get_mut(tab) else {
+            return;
+        };
+        *slot = ids.get(index).map(|id| Selected { id: id.to_string(), index });
+    }
+
+    // Select the entity with `id`, if the tab lists it
+    pub fn select_id(&mut self, tab: usize, status: &SnapcastStatus, id: &str) {
+        if let Some(index) = self.tab_ids(status, tab).iter().position(|candidate| *candidate == id) {
+            self.select_index(tab, status, index);
+        }
+    }
+
+    pub fn offset(&self, tab: usize) -> usize {
+        self.offsets.get(tab).map_or(0, Cell::get)
+    }
+
+    pub fn set_offset(&self, tab: usize, offset: usize) {
+        if let Some(cell) = self.offsets.get(tab) {
+            cell.set(offset);
+        }
+    }
+
+    pub fn clear(&mut self, tab: usize) {
+        if let Some(slot) = self.tabs.get_mut(tab) {
+            *slot = None;
+        }
+    }
+
+    pub fn is_expanded(&self, group_id: &str) -> bool {
+        !self.collapsed.contains(group_id)
+    }
+
+    // Fold or unfold a group in the tree
+    pub fn toggle_group(&mut self, group_id: &str) {
+        if !self.collapsed.remove(group_id) {
+            self.collapsed.insert(group_id.to_string());
+        }
+    }
+
+    // Follow entities that moved in their list, and when one is gone select
+    // the entity that took its place, or the new last one
+    pub fn sync(&mut self, status: &SnapcastStatus) {
+        let lists: Vec<Vec<&str>> = (0..self.tabs.len()).map(|tab| self.tab_ids(status, tab)).collect();
+
+        for (slot, ids) in self.tabs.iter_mut().zip(lists) {
+            let Some(selected) = slot else {
+                continue;
+            };
+
+            if let Some(index) = ids.iter().position(|id| *id == selected.id) {
+                selected.index = index;
+            } else if ids.is_empty() {
+                *slot = None;
+            } else {
+                let index = selected.index.min(ids.len() - 1);
+                *slot = Some(Selected { id: ids[index].to_string(), index });
+            }
+        }
+    }
+
+    // Groups with the members of expanded groups nested below them
+    pub fn group_tree<'a>(&self, status: &'a SnapcastStatus) -> Vec<TreeRow<'a>> {
+        let mut rows = Vec::new();
+        for group in &status.server.groups {
+            rows.push(TreeRow::Group(group));
+            if self.is_expanded(&group.id) {
+                rows.extend(group.clients.iter().map(|client| TreeRow::Client(group, client)));
+            }
+        }
+        rows
+    }
+
+    // IDs a tab lists, in the order they're shown
+    pub fn tab_ids<'a>(&self, status: &'a SnapcastStatus, tab: usize) -> Vec<&'a str> {
+        match tab {
+            0 => self.group_tree(status).iter().map(TreeRow::id).collect(),
+            1 => status.server.groups.iter()
+                .flat_map(|group| group.clients.iter())
+                .map(|client| client.id.as_str())
+                .collect(),
+            2 => status.server.streams.iter().map(|stream| stream.id.as_str()).collect(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+impl<'a> TreeRow<'a> {
+    pub fn id(&self) -> &'a str {
+        match self {
+            TreeRow::Group(group) => &group.id,
+            TreeRow::Client(_, client) => &client.id,
+        }
+    }
+
+    // Group of the row, or the group the client belongs to
+    pub fn group(&self) -> &'a Group {
+        match self {
+            TreeRow::Group(group) | TreeRow::Client(group, _) => group,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STATUS: &str = include_str!("../tests/fixtures/status-0.26.json");
+    const KITCHEN: &str = "dc:a6:32:0f:11:2b";
+    const DOWNSTAIRS: &str = "9f3e0a5c-2a14-4b7e-8a0e-5d1c6f7b3e21";
+    const UNNAMED_GROUP: &str = "1c2b3a4d-5e6f-4a7b-8c9d-0e1f2a3b4c5d";
+
+    // Tabs as Selection numbers them
+    const GROUPS: usize = 0;
+    const CLIENTS: usize = 1;
+    const STREAMS: usize = 2;
+
+    fn status() -> SnapcastStatus {
+        serde_json::from_str(STATUS).expect("fixture should parse")
+    }
+
+    #[test]
+    fn selects_by_index_and_id() {
+        let status = status();
+        let mut selection = Selection::default();
+
+        selection.select_index(CLIENTS, &status, 1);
+        assert_eq!(selection.id(CLIENTS), Some("desktop#2"));
+        selection.select_id(GROUPS, &status, UNNAMED_GROUP);
+        assert_eq!(selection.index(GROUPS, &status), Some(2));
+
+        // Past the end of the list clears the tab
+        selection.select_index(CLIENTS, &status, 5);
+        assert_eq!(selection.id(CLIENTS), None);
+    }
+
+    #[test]
+    fn follows_an_entity_that_moved() {
+        let mut status = status();
+        let mut selection = Selection::default();
+        selection.select_id(STREAMS, &status, "Mixed");
+
+        status.server.streams.remove(0);
+        selection.sync(&status);
+
+        assert_eq!(selection.id(STREAMS), Some("Mixed"));
+        assert_eq!(selection.index(STREAMS, &status), Some(1));
+    }
+
+    #[test]
+    fn moves_to_the_client_that_took_the_place_of_a_removed_one() {
+        let mut status = status();
+        let mut selection = Selection::default();
+        selection.select_id(CLIENTS, &status, KITCHEN);
+
+        status.server.groups[0].clients.clear();
+        selection.sync(&status);
+
+        assert_eq!(selection.id(CLIENTS), Some("desktop#2"));
+    }
+
+    #[test]
+    fn moves_to_the_new_last_client_when_the_last_one_is_removed() {
+        let mut status = status();
+        let mut selection = Selection::default();
+        selection.select_id(CLIENTS, &status, "desktop#2");
+
+        status.server.groups.retain(|group| group.id != UNNAMED_GROUP);
+        selection.sync(&status);
+
+        assert_eq!(selection.id(CLIENTS), Some(KITCHEN));
+    }
+
+    #[test]
+    fn moves_to_the_next_row_of_the_tree_when_a_group_is_removed() {
+        let mut status = status();
+        let mut selection = Selection::default();
+        selection.select_id(GROUPS, &status, DOWNSTAIRS);
+
+        status.server.groups.retain(|group| group.id != DOWNSTAIRS);
+        selection.sync(&status);
+
+        assert_eq!(selection.id(GROUPS), Some(UNNAMED_GROUP));
+    }
+
+    #[test]
+    fn clears_the_selection_once_the_list_is_empty() {
+        let mut status = status();
+        let mut selection = Selection::default();
+        selection.select_id(GROUPS, &status, DOWNSTAIRS);
+        selection.select_id(CLIENTS, &status, KITCHEN);
+
+        status.server.groups.clear();
+        selection.sync(&status);
+
+        assert_eq!(selection.id(GROUPS), None);
+        assert_eq!(selection.id(CLIENTS), None);
+    }
+}
@@ -0,0 +1,104 @@
+use std::fmt;
+use tokio_tungstenite::tungstenite;
+
+// JSON-RPC error codes the server uses
+pub const PARSE_ERROR: i64 = -32700;
+pub const INVALID_REQUEST: i64 = -32600;
+pub const METHOD_NOT_FOUND: i64 = -32601;
+pub const INVALID_PARAMS: i64 = -32602;
+pub const INTERNAL_ERROR: i64 = -32603;
+
+// Everything that can go wrong talking to a snapserver
+#[derive(Debug)]
+pub enum SnapcastError {
+    // TCP connect or WebSocket handshake failed, e.g. connection refused
+    Connect(tungstenite::Error),
+    // The session closed while a request was in flight
+    Closed,
+    // The server didn't answer in time
+    Timeout { method: String },
+    // The server answered with a JSON-RPC error object
+    Rpc { method: String, code: i64, message: String },
+    // The server answered with something our structs don't match
+    Schema(serde_json::Error),
+    // The request can't be made with what we know, e.g. an unknown client
+    Invalid(String),
+}
+
+impl SnapcastError {
+    // Failures that may go away by reconnecting or asking again
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            // A bad URL, a TLS scheme or an HTTP answer such as a 404 for a
+            // wrong path won't fix itself, only the network going away will
+            SnapcastError::Connect(e) => matches!(
+                e,
+                tungstenite::Error::Io(_) | tungstenite::Error::ConnectionClosed | tungstenite::Error::AlreadyClosed
+            ),
+            SnapcastError::Closed | SnapcastError::Timeout { .. } => true,
+            _ => false,
+        }
+    }
+
+    // JSON-RPC error code, if the server rejected the request
+    pub fn rpc_code(&self) -> Option<i64> {
+        match self {
+            SnapcastError::Rpc { code, .. } => Some(*code),
+            _ => None,
+        }
+    }
+
+    // What the user can do about it
+    pub fn hint(&self) -> Option<&'static str> {
+        match self {
+            SnapcastError::Connect(tungstenite::Error::Url(_)) => Some("check the host and scheme of the server"),
+            SnapcastError::Connect(tungstenite::Error::Http(_)) => Some("the server isn't speaking WebSocket there, check the path"),
+            SnapcastError::Connect(_) => Some("check that snapserver is running and the host and port are right"),
+            SnapcastError::Closed => Some("the server went away, press 'r' to reconnect"),
+            SnapcastError::Timeout { .. } => Some("the server is slow to answer, try again"),
+            SnapcastError::Rpc { code: METHOD_NOT_FOUND, .. } => Some("this snapserver version doesn't support it"),
+            SnapcastError::Rpc { code: INVALID_PARAMS, .. } => Some("the server rejected the values sent"),
+            SnapcastError::Schema(_) => Some("this snapserver version sends data snap-tui can't read"),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for SnapcastError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SnapcastError::Connect(e) => write!(f, "Can't connect: {}", e),
+            SnapcastError::Closed => write!(f, "Connection closed"),
+            SnapcastError::Timeout { method } => write!(f, "{} timed out", method),
+            SnapcastError::Rpc { method, code, message } => {
+                let kind = match *code {
+                    PARSE_ERROR => "parse error",
+                    INVALID_REQUEST => "invalid request",
+                    METHOD_NOT_FOUND => "method not found",
+                    INVALID_PARAMS => "invalid params",
+                    INTERNAL_ERROR => "internal error",
+                    _ => "error",
+                };
+                write!(f, "{} failed with {} {}: {}", method, kind, code, message)
+            }
+            SnapcastError::Schema(e) => write!(f, "Unexpected server data: {}", e),
+            SnapcastError::Invalid(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for SnapcastError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SnapcastError::Connect(e) => Some(e),
+            SnapcastError::Schema(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for SnapcastError {
+    fn from(e: serde_json::Error) -> Self {
+        SnapcastError::Schema(e)
+    }
+}
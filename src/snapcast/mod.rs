@@ -0,0 +1,951 @@
+mod error;
+mod notification;
+#[cfg(test)]
+mod tests;
+
+pub use error::SnapcastError;
+pub use notification::Notification;
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::{json, Value};
+use tokio::{
+    net::TcpStream,
+    sync::{mpsc, oneshot},
+    task::JoinHandle,
+};
+use tokio_tungstenite::{connect_async, tungstenite::Message, MaybeTlsStream, WebSocketStream};
+use uuid::Uuid;
+use futures::{stream::SplitStream, SinkExt, StreamExt};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+// How long to wait for the server to answer a single request
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+// Requests waiting for a response, keyed by JSON-RPC id
+type PendingRequests = Arc<Mutex<HashMap<String, oneshot::Sender<SnapcastResponse>>>>;
+
+// Main client struct
+#[derive(Debug)]
+pub struct SnapcastClient {
+    url: String,
+    pub status: Option<SnapcastStatus>,
+    session: Option<Session>,
+    notifications: Option<mpsc::UnboundedReceiver<Notification>>,
+}
+
+// Open WebSocket connection with its reader and writer tasks
+#[derive(Debug)]
+struct Session {
+    outgoing: mpsc::UnboundedSender<Message>,
+    pending: PendingRequests,
+    reader: JoinHandle<()>,
+    writer: JoinHandle<()>,
+}
+
+// Response wrapper that matches the top-level JSON structure
+#[derive(Debug, Deserialize)]
+pub struct SnapcastResponse {
+    pub id: String,
+    pub jsonrpc: String,
+    pub result: Option<Value>,
+    pub error: Option<RpcError>,
+}
+
+// JSON-RPC error object returned instead of a result
+#[derive(Debug, Deserialize)]
+pub struct RpcError {
+    pub code: i64,
+    pub message: String,
+    pub data: Option<Value>,
+}
+
+// Main status container
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SnapcastStatus {
+    pub server: ServerStatus,
+}
+
+// Server status containing streams and groups
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ServerStatus {
+    pub server: ServerInfo,
+    pub streams: Vec<Stream>,
+    pub groups: Vec<Group>,
+}
+
+// Server information
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ServerInfo {
+    pub host: HostInfo,
+    pub snapserver: SnapserverInfo,
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+// Fields a newer or older server sends that we don't model
+pub type Extra = serde_json::Map<String, Value>;
+
+// Host information
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct HostInfo {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub os: String,
+    #[serde(default)]
+    pub arch: String,
+    #[serde(default)]
+    pub ip: String,
+    // Not reported by every snapclient, e.g. on platforms without a readable MAC
+    #[serde(default)]
+    pub mac: String,
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+// Snapserver information
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SnapserverInfo {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub version: String,
+    #[serde(rename = "controlProtocolVersion")]
+    pub control_protocol_version: Option<u32>,
+    #[serde(rename = "protocolVersion", default)]
+    pub protocol_version: u32,
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+// Stream information
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Stream {
+    pub id: String,
+    #[serde(default)]
+    pub status: String,
+    pub uri: Uri,
+    // Servers before 0.26 don't report stream properties at all
+    #[serde(default)]
+    pub properties: StreamProperties,
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+// Stream properties
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct StreamProperties {
+    #[serde(rename = "canControl", default)]
+    pub can_control: bool,
+    #[serde(rename = "canPlay", default)]
+    pub can_play: bool,
+    #[serde(rename = "canPause", default)]
+    pub can_pause: bool,
+    #[serde(rename = "canSeek", default)]
+    pub can_seek: bool,
+    #[serde(rename = "canGoNext", default)]
+    pub can_go_next: bool,
+    #[serde(rename = "canGoPrevious", default)]
+    pub can_go_previous: bool,
+    #[serde(rename = "playbackStatus")]
+    pub playback_status: Option<String>,
+    #[serde(rename = "loopStatus")]
+    pub loop_status: Option<String>,
+    pub shuffle: Option<bool>,
+    pub volume: Option<u32>,
+    pub mute: Option<bool>,
+    pub rate: Option<f64>,
+    // Playback position in seconds at `received_at`
+    pub position: Option<f64>,
+    pub metadata: Option<Metadata>,
+    // When these properties arrived, to advance the position locally
+    #[serde(skip, default = "Instant::now")]
+    pub received_at: Instant,
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+// Now playing metadata of a stream
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Metadata {
+    pub title: Option<String>,
+    // MPRIS says a list, but some stream plugins send a single string
+    #[serde(default, deserialize_with = "string_or_list")]
+    pub artist: Vec<String>,
+    pub album: Option<String>,
+    // Track length in seconds
+    pub duration: Option<f64>,
+    #[serde(rename = "artUrl")]
+    pub art_url: Option<String>,
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+// URI information
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Uri {
+    #[serde(default)]
+    pub path: String,
+    pub scheme: String,
+    #[serde(default)]
+    pub query: Query,
+    #[serde(default)]
+    pub fragment: String,
+    #[serde(default)]
+    pub host: String,
+    #[serde(default)]
+    pub raw: String,
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+// URI query parameters
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct Query {
+    // Meta and airplay streams may come without one, see `Stream::display_name`
+    #[serde(default)]
+    pub name: String,
+    #[serde(rename = "chunk_ms")]
+    pub chunk_ms: Option<String>,
+    pub codec: Option<String>,
+    pub mode: Option<String>,
+    #[serde(rename = "sampleformat")]
+    pub sample_format: Option<String>,
+    // Plugin specific parameters like buffer, device or dryout_ms
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+// Group information
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Group {
+    pub id: String,
+    #[serde(default)]
+    pub name: String,
+    #[serde(rename = "stream_id")]
+    pub stream_id: String,
+    #[serde(default)]
+    pub muted: bool,
+    #[serde(default)]
+    pub clients: Vec<Client>,
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+// Client information
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Client {
+    pub id: String,
+    #[serde(rename = "host", default)]
+    pub host: HostInfo,
+    #[serde(rename = "snapclient", default)]
+    pub snapclient: SnapclientInfo,
+    #[serde(rename = "config")]
+    pub config: ClientConfig,
+    pub connected: bool,
+    #[serde(rename = "lastSeen", default)]
+    pub last_seen: LastSeen,
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+// Client configuration
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ClientConfig {
+    // Only sent by servers that know about multiple clients per host
+    #[serde(default = "default_instance")]
+    pub instance: u32,
+    #[serde(default)]
+    pub latency: u32,
+    #[serde(default)]
+    pub name: String,
+    pub volume: Volume,
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+// Volume information
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Volume {
+    pub muted: bool,
+    pub percent: u32,
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+// Snapclient information
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct SnapclientInfo {
+    #[serde(default)]
+    pub name: String,
+    #[serde(rename = "protocolVersion", default)]
+    pub protocol_version: u32,
+    #[serde(default)]
+    pub version: String,
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+// Last seen timestamp
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct LastSeen {
+    pub sec: u64,
+    pub usec: u64,
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+impl Default for StreamProperties {
+    fn default() -> Self {
+        StreamProperties {
+            can_control: false,
+            can_play: false,
+            can_pause: false,
+            can_seek: false,
+            can_go_next: false,
+            can_go_previous: false,
+            playback_status: None,
+            loop_status: None,
+            shuffle: None,
+            volume: None,
+            mute: None,
+            rate: None,
+            position: None,
+            metadata: None,
+            received_at: Instant::now(),
+            extra: Extra::new(),
+        }
+    }
+}
+
+// Snapcast numbers client instances from 1
+fn default_instance() -> u32 {
+    1
+}
+
+fn string_or_list<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StringOrList {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    Ok(match Option::<StringOrList>::deserialize(deserializer)? {
+        Some(StringOrList::One(value)) => vec![value],
+        Some(StringOrList::Many(values)) => values,
+        None => Vec::new(),
+    })
+}
+
+impl ServerStatus {
+    // Keep the position clock of every stream that carries on from `previous`
+    pub fn keep_clocks(&mut self, previous: &ServerStatus) {
+        for stream in &mut self.streams {
+            if let Some(old) = previous.streams.iter().find(|old| old.id == stream.id) {
+                stream.properties.keep_clock(&old.properties);
+            }
+        }
+    }
+}
+
+impl SnapcastStatus {
+    // Disconnected clients last seen more than `max_age` ago
+    pub fn stale_clients(&self, max_age: Duration) -> Vec<&Client> {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+
+        self.server.groups.iter()
+            .flat_map(|group| group.clients.iter())
+            .filter(|client| !client.connected)
+            .filter(|client| now.saturating_sub(client.last_seen.sec) >= max_age.as_secs())
+            .collect()
+    }
+
+    // Same, in days. Saturates so a huge number of days means "never", not a panic.
+    pub fn stale_clients_for_days(&self, days: u64) -> Vec<&Client> {
+        self.stale_clients(Duration::from_secs(days.saturating_mul(24 * 60 * 60)))
+    }
+
+    pub fn client_mut(&mut self, id: &str) -> Option<&mut Client> {
+        self.server.groups.iter_mut()
+            .flat_map(|group| group.clients.iter_mut())
+            .find(|client| client.id == id)
+    }
+
+    pub fn group_mut(&mut self, id: &str) -> Option<&mut Group> {
+        self.server.groups.iter_mut().find(|group| group.id == id)
+    }
+
+    pub fn stream_mut(&mut self, id: &str) -> Option<&mut Stream> {
+        self.server.streams.iter_mut().find(|stream| stream.id == id)
+    }
+}
+
+// Transport commands accepted by Stream.Control
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StreamCommand {
+    Play,
+    Pause,
+    PlayPause,
+    Next,
+    Previous,
+    // Relative seek in seconds
+    Seek(f64),
+}
+
+impl StreamCommand {
+    pub fn name(&self) -> &'static str {
+        match self {
+            StreamCommand::Play => "play",
+            StreamCommand::Pause => "pause",
+            StreamCommand::PlayPause => "playPause",
+            StreamCommand::Next => "next",
+            StreamCommand::Previous => "previous",
+            StreamCommand::Seek(_) => "seek",
+        }
+    }
+
+    // Whether the stream advertises the capability this command needs
+    pub fn is_supported(&self, properties: &StreamProperties) -> bool {
+        match self {
+            StreamCommand::Play => properties.can_play,
+            StreamCommand::Pause => properties.can_pause,
+            StreamCommand::PlayPause => properties.can_play || properties.can_pause,
+            StreamCommand::Next => properties.can_go_next,
+            StreamCommand::Previous => properties.can_go_previous,
+            StreamCommand::Seek(_) => properties.can_seek,
+        }
+    }
+}
+
+impl StreamProperties {
+    pub fn is_playing(&self) -> bool {
+        self.playback_status.as_deref() == Some("playing")
+    }
+
+    // Keep advancing from `previous` when these properties are the same track
+    // in the same state and bring no newer position. Full status updates repeat
+    // the position the server last reported, taking it as new would make the
+    // progress bar jump back.
+    pub fn keep_clock(&mut self, previous: &StreamProperties) {
+        let same_track = match (&self.metadata, &previous.metadata) {
+            (Some(new), Some(old)) => {
+                new.title == old.title && new.artist == old.artist && new.album == old.album && new.duration == old.duration
+            }
+            (None, None) => true,
+            _ => false,
+        };
+
+        if same_track
+            && self.playback_status == previous.playback_status
+            && (self.position.is_none() || self.position == previous.position)
+        {
+            self.position = previous.position;
+            self.received_at = previous.received_at;
+        }
+    }
+
+    // Position extrapolated from the last update while the stream is playing
+    pub fn current_position(&self) -> Option<f64> {
+        let position = self.position?;
+        if !self.is_playing() {
+            return Some(position);
+        }
+
+        let elapsed = self.received_at.elapsed().as_secs_f64() * self.rate.unwrap_or(1.0);
+        let duration = self.metadata.as_ref().and_then(|metadata| metadata.duration);
+        Some(match duration {
+            Some(duration) => (position + elapsed).min(duration),
+            None => position + elapsed,
+        })
+    }
+}
+
+impl Group {
+    // Friendly name, falling back to the group id
+    pub fn display_name(&self) -> &str {
+        if self.name.is_empty() { &self.id } else { &self.name }
+    }
+
+    // Average volume of the connected clients, like snapweb's group slider
+    pub fn volume(&self) -> Option<u32> {
+        let volumes: Vec<u32> = self.clients.iter()
+            .filter(|client| client.connected)
+            .map(|client| client.config.volume.percent)
+            .collect();
+
+        if volumes.is_empty() {
+            return None;
+        }
+        Some(volumes.iter().sum::<u32>() / volumes.len() as u32)
+    }
+
+    // New volume of each connected client when the group volume goes to `percent`.
+    // Clients move proportionally to their distance from 0 (going down) or 100 (going up).
+    pub fn scaled_volumes(&self, percent: u32) -> Vec<(String, u32)> {
+        let Some(current) = self.volume() else {
+            return Vec::new();
+        };
+        let percent = percent.min(100) as f64;
+        let current = current as f64;
+
+        self.clients.iter()
+            .filter(|client| client.connected)
+            .map(|client| {
+                let volume = client.config.volume.percent as f64;
+                let scaled = if percent < current {
+                    volume - volume * (current - percent) / current
+                } else if current < 100.0 {
+                    volume + (100.0 - volume) * (percent - current) / (100.0 - current)
+                } else {
+                    volume
+                };
+                (client.id.clone(), scaled.round().clamp(0.0, 100.0) as u32)
+            })
+            .collect()
+    }
+}
+
+impl Client {
+    // Friendly name, falling back to the hostname and then the client id
+    pub fn display_name(&self) -> &str {
+        if !self.config.name.is_empty() {
+            &self.config.name
+        } else if !self.host.name.is_empty() {
+            &self.host.name
+        } else {
+            &self.id
+        }
+    }
+}
+
+impl Stream {
+    // Name from the stream URI, falling back to the stream id
+    pub fn display_name(&self) -> &str {
+        if self.uri.query.name.is_empty() {
+            &self.id
+        } else {
+            &self.uri.query.name
+        }
+    }
+
+    // One line "title - artist" summary, if the stream has metadata
+    pub fn now_playing_summary(&self) -> Option<String> {
+        let metadata = self.properties.metadata.as_ref()?;
+        let title = metadata.title.as_deref()?;
+
+        if metadata.artist.is_empty() {
+            Some(title.to_string())
+        } else {
+            Some(format!("{} - {}", title, metadata.artist.join(", ")))
+        }
+    }
+}
+
+impl Session {
+    async fn open(url: &str) -> Result<(Self, mpsc::UnboundedReceiver<Notification>), SnapcastError> {
+        // A host that drops packets would otherwise hold the caller until the
+        // OS gives up on the TCP connect, which takes minutes
+        let (ws_stream, _) = match tokio::time::timeout(REQUEST_TIMEOUT, connect_async(url)).await {
+            Ok(result) => result.map_err(SnapcastError::Connect)?,
+            Err(_) => return Err(SnapcastError::Timeout { method: format!("Connecting to {}", url) }),
+        };
+        let (mut write, read) = ws_stream.split();
+
+        let pending: PendingRequests = Arc::new(Mutex::new(HashMap::new()));
+        let (outgoing, mut outgoing_rx) = mpsc::unbounded_channel::<Message>();
+
+        let writer = tokio::spawn(async move {
+            while let Some(message) = outgoing_rx.recv().await {
+                if write.send(message).await.is_err() {
+                    break;
+                }
+            }
+            let _ = write.close().await;
+        });
+
+        let (notifications, notifications_rx) = mpsc::unbounded_channel();
+        let reader = tokio::spawn(read_messages(read, pending.clone(), notifications));
+
+        Ok((Session { outgoing, pending, reader, writer }, notifications_rx))
+    }
+
+    fn is_alive(&self) -> bool {
+        !self.reader.is_finished() && !self.writer.is_finished()
+    }
+}
+
+impl Drop for Session {
+    fn drop(&mut self) {
+        self.reader.abort();
+        self.writer.abort();
+    }
+}
+
+// Route responses to the request waiting for them and forward notifications
+async fn read_messages(
+    mut read: SplitStream<WebSocketStream<MaybeTlsStream<TcpStream>>>,
+    pending: PendingRequests,
+    notifications: mpsc::UnboundedSender<Notification>,
+) {
+    while let Some(Ok(message)) = read.next().await {
+        let text = match message {
+            Message::Text(text) => text,
+            Message::Close(_) => break,
+            _ => continue,
+        };
+
+        let Ok(value) = serde_json::from_str::<Value>(&text) else {
+            continue;
+        };
+
+        // Frames without an id are server notifications, not responses
+        if value.get("id").is_none_or(Value::is_null) {
+            // Methods we don't model are ignored
+            if let Ok(notification) = serde_json::from_value::<Notification>(value) {
+                let _ = notifications.send(notification);
+            }
+            continue;
+        }
+
+        if let Ok(response) = serde_json::from_value::<SnapcastResponse>(value) {
+            let waiter = pending.lock().unwrap().remove(&response.id);
+            if let Some(waiter) = waiter {
+                let _ = waiter.send(response);
+            }
+        }
+    }
+
+    // Dropping the senders wakes every caller still waiting on this session
+    pending.lock().unwrap().clear();
+}
+
+impl SnapcastClient {
+    pub fn new(url: String) -> Self {
+        SnapcastClient { url, status: None, session: None, notifications: None }
+    }
+
+    pub fn is_connected(&self) -> bool {
+        self.session.as_ref().is_some_and(Session::is_alive)
+    }
+
+    // Open a session unless one is already up
+    pub async fn connect(&mut self) -> Result<(), SnapcastError> {
+        if !self.is_connected() {
+            self.session = None;
+            let (session, notifications) = Session::open(&self.url).await?;
+            self.session = Some(session);
+            self.notifications = Some(notifications);
+        }
+        Ok(())
+    }
+
+    // Apply every notification received since the last call to the cached status
+    pub fn process_notifications(&mut self) -> usize {
+        let Some(receiver) = &mut self.notifications else {
+            return 0;
+        };
+
+        let mut applied = 0;
+        while let Ok(notification) = receiver.try_recv() {
+            if let Some(status) = &mut self.status {
+                status.apply(notification);
+                applied += 1;
+            }
+        }
+        applied
+    }
+
+    pub fn disconnect(&mut self) {
+        self.session = None;
+    }
+
+    // Send a JSON-RPC request and wait for the response carrying the same id
+    pub async fn request(&mut self, method: &str, params: Option<Value>) -> Result<Value, SnapcastError> {
+        self.connect().await?;
+        let Some(session) = &self.session else {
+            return Err(SnapcastError::Closed);
+        };
+
+        let request_id = Uuid::new_v4().to_string();
+        let mut request = json!({
+            "id": request_id,
+            "jsonrpc": "2.0",
+            "method": method
+        });
+        if let Some(params) = params {
+            request["params"] = params;
+        }
+
+        let (tx, rx) = oneshot::channel();
+        session.pending.lock().unwrap().insert(request_id.clone(), tx);
+
+        if session.outgoing.send(Message::Text(request.to_string())).is_err() {
+            self.session = None;
+            return Err(SnapcastError::Closed);
+        }
+
+        let response = match tokio::time::timeout(REQUEST_TIMEOUT, rx).await {
+            Ok(Ok(response)) => response,
+            Ok(Err(_)) => {
+                self.session = None;
+                return Err(SnapcastError::Closed);
+            }
+            Err(_) => {
+                session.pending.lock().unwrap().remove(&request_id);
+                return Err(SnapcastError::Timeout { method: method.to_string() });
+            }
+        };
+
+        if let Some(error) = response.error {
+            return Err(SnapcastError::Rpc {
+                method: method.to_string(),
+                code: error.code,
+                message: error.message,
+            });
+        }
+
+        Ok(response.result.unwrap_or(Value::Null))
+    }
+
+    pub async fn fetch_status(&mut self) -> Result<(), SnapcastError> {
+        // A session that died while idle is only noticed here, so give it one more go
+        let result = match self.request("Server.GetStatus", None).await {
+            Err(SnapcastError::Closed) => self.request("Server.GetStatus", None).await?,
+            result => result?,
+        };
+        let mut status: SnapcastStatus = serde_json::from_value(result)?;
+        if let Some(previous) = &self.status {
+            status.server.keep_clocks(&previous.server);
+        }
+        self.status = Some(status);
+
+        Ok(())
+    }
+
+    // Show `value` in the cached status right away, then keep what the server confirms
+    // under `reply_key`, or put the old value back if the request fails
+    async fn set_field<T, F>(
+        &mut self,
+        method: &str,
+        params: Value,
+        reply_key: &str,
+        value: T,
+        field: F,
+    ) -> Result<(), SnapcastError>
+    where
+        T: DeserializeOwned,
+        F: Fn(&mut SnapcastStatus) -> Option<&mut T>,
+    {
+        let previous = self.status.as_mut()
+            .and_then(&field)
+            .map(|current| std::mem::replace(current, value));
+
+        match self.request(method, Some(params)).await {
+            Ok(result) => {
+                if let Ok(confirmed) = serde_json::from_value::<T>(result[reply_key].clone())
+                    && let Some(current) = self.status.as_mut().and_then(&field)
+                {
+                    *current = confirmed;
+                }
+                Ok(())
+            }
+            Err(e) => {
+                if let Some(previous) = previous
+                    && let Some(current) = self.status.as_mut().and_then(&field)
+                {
+                    *current = previous;
+                }
+                Err(e)
+            }
+        }
+    }
+
+    pub async fn set_client_volume(&mut self, id: &str, volume: Volume) -> Result<(), SnapcastError> {
+        let params = json!({ "id": id, "volume": volume });
+        self.set_field("Client.SetVolume", params, "volume", volume, |status| {
+            status.client_mut(id).map(|client| &mut client.config.volume)
+        }).await
+    }
+
+    pub async fn set_client_name(&mut self, id: &str, name: &str) -> Result<(), SnapcastError> {
+        let params = json!({ "id": id, "name": name });
+        self.set_field("Client.SetName", params, "name", name.to_string(), |status| {
+            status.client_mut(id).map(|client| &mut client.config.name)
+        }).await
+    }
+
+    pub async fn set_client_latency(&mut self, id: &str, latency: u32) -> Result<(), SnapcastError> {
+        let params = json!({ "id": id, "latency": latency });
+        self.set_field("Client.SetLatency", params, "latency", latency, |status| {
+            status.client_mut(id).map(|client| &mut client.config.latency)
+        }).await
+    }
+
+    pub async fn set_group_stream(&mut self, id: &str, stream_id: &str) -> Result<(), SnapcastError> {
+        let params = json!({ "id": id, "stream_id": stream_id });
+        self.set_field("Group.SetStream", params, "stream_id", stream_id.to_string(), |status| {
+            status.group_mut(id).map(|group| &mut group.stream_id)
+        }).await
+    }
+
+    pub async fn set_group_mute(&mut self, id: &str, mute: bool) -> Result<(), SnapcastError> {
+        let params = json!({ "id": id, "mute": mute });
+        self.set_field("Group.SetMute", params, "mute", mute, |status| {
+            status.group_mut(id).map(|group| &mut group.muted)
+        }).await
+    }
+
+    // Scale the volume of every connected member, one Client.SetVolume each
+    pub async fn set_group_volume(&mut self, id: &str, percent: u32) -> Result<(), SnapcastError> {
+        let group = self.status.as_ref()
+            .and_then(|status| status.server.groups.iter().find(|group| group.id == id))
+            .ok_or_else(|| SnapcastError::Invalid(format!("Unknown group {}", id)))?;
+
+        let volumes = group.scaled_volumes(percent);
+        if volumes.is_empty() {
+            return Err(SnapcastError::Invalid("No connected clients in group".to_string()));
+        }
+
+        let members: Vec<(String, Volume, u32)> = volumes.into_iter()
+            .filter_map(|(client_id, percent)| {
+                let client = group.clients.iter().find(|client| client.id == client_id)?;
+                Some((client_id, client.config.volume.clone(), percent))
+            })
+            .collect();
+
+        for (client_id, volume, percent) in members {
+            if volume.percent != percent {
+                self.set_client_volume(&client_id, Volume { percent, ..volume }).await?;
+            }
+        }
+        Ok(())
+    }
+
+    pub async fn set_group_name(&mut self, id: &str, name: &str) -> Result<(), SnapcastError> {
+        let params = json!({ "id": id, "name": name });
+        self.set_field("Group.SetName", params, "name", name.to_string(), |status| {
+            status.group_mut(id).map(|group| &mut group.name)
+        }).await
+    }
+
+    pub async fn control_stream(&mut self, id: &str, command: StreamCommand) -> Result<(), SnapcastError> {
+        let mut params = json!({ "id": id, "command": command.name() });
+        if let StreamCommand::Seek(offset) = command {
+            params["params"] = json!({ "offset": offset });
+        }
+
+        // Playback changes come back as Stream.OnProperties notifications
+        self.request("Stream.Control", Some(params)).await?;
+        Ok(())
+    }
+
+    // Change loopStatus, shuffle, volume, mute or rate on a controllable stream.
+    // The confirmed value comes back as a Stream.OnProperties notification.
+    pub async fn set_stream_property(&mut self, id: &str, property: &str, value: Value) -> Result<(), SnapcastError> {
+        let params = json!({ "id": id, "property": property, "value": value });
+        self.request("Stream.SetProperty", Some(params)).await?;
+        Ok(())
+    }
+
+    // Create a stream from a URI like `pipe:///tmp/snapfifo?name=Radio`, returns its id
+    pub async fn add_stream(&mut self, stream_uri: &str) -> Result<String, SnapcastError> {
+        let params = json!({ "streamUri": stream_uri });
+        let result = self.request("Stream.AddStream", Some(params)).await?;
+        let stream_id = result["stream_id"].as_str().unwrap_or_default().to_string();
+
+        // Pick up the new stream with its parsed uri and properties
+        self.fetch_status().await?;
+        Ok(stream_id)
+    }
+
+    pub async fn remove_stream(&mut self, id: &str) -> Result<(), SnapcastError> {
+        let params = json!({ "id": id });
+        self.request("Stream.RemoveStream", Some(params)).await?;
+
+        // Groups that played the stream have been switched to another one
+        self.fetch_status().await
+    }
+
+    // Forget a client, the server answers with the whole new topology
+    pub async fn delete_client(&mut self, id: &str) -> Result<(), SnapcastError> {
+        let params = json!({ "id": id });
+        let result = self.request("Server.DeleteClient", Some(params)).await?;
+        let mut server: ServerStatus = serde_json::from_value(result["server"].clone())?;
+
+        match &mut self.status {
+            Some(status) => {
+                server.keep_clocks(&status.server);
+                status.server = server;
+            }
+            None => self.status = Some(SnapcastStatus { server }),
+        }
+        Ok(())
+    }
+
+    // Replace the members of a group, the server answers with the whole new topology
+    pub async fn set_group_clients(&mut self, id: &str, clients: Vec<String>) -> Result<(), SnapcastError> {
+        let params = json!({ "id": id, "clients": clients });
+        let result = self.request("Group.SetClients", Some(params)).await?;
+        let mut server: ServerStatus = serde_json::from_value(result["server"].clone())?;
+
+        match &mut self.status {
+            Some(status) => {
+                server.keep_clocks(&status.server);
+                status.server = server;
+            }
+            None => self.status = Some(SnapcastStatus { server }),
+        }
+        Ok(())
+    }
+
+    // Move a client into another group, or into a new group of its own when `target` is None
+    pub async fn move_client(&mut self, client_id: &str, target: Option<&str>) -> Result<(), SnapcastError> {
+        let status = self.status.as_ref()
+            .ok_or_else(|| SnapcastError::Invalid("No status available".to_string()))?;
+        let source = status.server.groups.iter()
+            .find(|group| group.clients.iter().any(|client| client.id == client_id))
+            .ok_or_else(|| SnapcastError::Invalid(format!("Unknown client {}", client_id)))?;
+
+        match target {
+            Some(target_id) => {
+                let target = status.server.groups.iter()
+                    .find(|group| group.id == target_id)
+                    .ok_or_else(|| SnapcastError::Invalid(format!("Unknown group {}", target_id)))?;
+                if target.id == source.id {
+                    return Ok(());
+                }
+
+                // The server takes the client out of its old group by itself
+                let mut clients: Vec<String> = target.clients.iter().map(|client| client.id.clone()).collect();
+                clients.push(client_id.to_string());
+                let target_id = target.id.clone();
+                self.set_group_clients(&target_id, clients).await
+            }
+            None => {
+                if source.clients.len() == 1 {
+                    return Err(SnapcastError::Invalid("Client is already alone in its group".to_string()));
+                }
+
+                // Clients dropped from a group get a new group of their own
+                let clients: Vec<String> = source.clients.iter()
+                    .filter(|client| client.id != client_id)
+                    .map(|client| client.id.clone())
+                    .collect();
+                let source_id = source.id.clone();
+                self.set_group_clients(&source_id, clients).await
+            }
+        }
+    }
+
+    pub fn get_url(&self) -> &str {
+        &self.url
+    }
+}
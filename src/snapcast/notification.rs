@@ -0,0 +1,96 @@
+use serde::Deserialize;
+
+use super::{Client, ServerStatus, SnapcastStatus, Stream, StreamProperties, Volume};
+
+// Notifications pushed by the server, tagged by their JSON-RPC method.
+// Whole clients and streams are boxed to keep the small variants small.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "method", content = "params")]
+pub enum Notification {
+    #[serde(rename = "Client.OnConnect")]
+    ClientConnect { id: String, client: Box<Client> },
+    #[serde(rename = "Client.OnDisconnect")]
+    ClientDisconnect { id: String, client: Box<Client> },
+    #[serde(rename = "Client.OnVolumeChanged")]
+    ClientVolumeChanged { id: String, volume: Volume },
+    #[serde(rename = "Client.OnLatencyChanged")]
+    ClientLatencyChanged { id: String, latency: u32 },
+    #[serde(rename = "Client.OnNameChanged")]
+    ClientNameChanged { id: String, name: String },
+    #[serde(rename = "Group.OnMute")]
+    GroupMute { id: String, mute: bool },
+    #[serde(rename = "Group.OnStreamChanged")]
+    GroupStreamChanged { id: String, stream_id: String },
+    #[serde(rename = "Group.OnNameChanged")]
+    GroupNameChanged { id: String, name: String },
+    #[serde(rename = "Stream.OnUpdate")]
+    StreamUpdate { id: String, stream: Box<Stream> },
+    #[serde(rename = "Stream.OnProperties")]
+    StreamProperties { id: String, properties: StreamProperties },
+    #[serde(rename = "Server.OnUpdate")]
+    ServerUpdate { server: ServerStatus },
+}
+
+impl SnapcastStatus {
+    // Fold a server notification into the cached status
+    pub fn apply(&mut self, notification: Notification) {
+        match notification {
+            Notification::ClientConnect { id, client } | Notification::ClientDisconnect { id, client } => {
+                // Unknown clients arrive with a Server.OnUpdate that places them in a group
+                if let Some(existing) = self.client_mut(&id) {
+                    *existing = *client;
+                }
+            }
+            Notification::ClientVolumeChanged { id, volume } => {
+                if let Some(client) = self.client_mut(&id) {
+                    client.config.volume = volume;
+                }
+            }
+            Notification::ClientLatencyChanged { id, latency } => {
+                if let Some(client) = self.client_mut(&id) {
+                    client.config.latency = latency;
+                }
+            }
+            Notification::ClientNameChanged { id, name } => {
+                if let Some(client) = self.client_mut(&id) {
+                    client.config.name = name;
+                }
+            }
+            Notification::GroupMute { id, mute } => {
+                if let Some(group) = self.group_mut(&id) {
+                    group.muted = mute;
+                }
+            }
+            Notification::GroupStreamChanged { id, stream_id } => {
+                if let Some(group) = self.group_mut(&id) {
+                    group.stream_id = stream_id;
+                }
+            }
+            Notification::GroupNameChanged { id, name } => {
+                if let Some(group) = self.group_mut(&id) {
+                    group.name = name;
+                }
+            }
+            Notification::StreamUpdate { id, stream } => {
+                match self.stream_mut(&id) {
+                    Some(existing) => {
+                        let mut stream = *stream;
+                        stream.properties.keep_clock(&existing.properties);
+                        *existing = stream;
+                    }
+                    None => self.server.streams.push(*stream),
+                }
+            }
+            Notification::StreamProperties { id, mut properties } => {
+                if let Some(stream) = self.stream_mut(&id) {
+                    properties.keep_clock(&stream.properties);
+                    stream.properties = properties;
+                }
+            }
+            Notification::ServerUpdate { mut server } => {
+                server.keep_clocks(&self.server);
+                self.server = server;
+            }
+        }
+    }
+}
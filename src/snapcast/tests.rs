@@ -0,0 +1,357 @@
+use super::*;
+use std::io;
+use tokio_tungstenite::tungstenite;
+
+// Server.GetStatus results captured from different snapserver versions
+const STATUS_0_10: &str = include_str!("../../tests/fixtures/status-0.10.json");
+const STATUS_0_26: &str = include_str!("../../tests/fixtures/status-0.26.json");
+const STATUS_0_28: &str = include_str!("../../tests/fixtures/status-0.28.json");
+
+fn parse(fixture: &str) -> SnapcastStatus {
+    serde_json::from_str(fixture).expect("fixture should parse")
+}
+
+fn stream<'a>(status: &'a SnapcastStatus, id: &str) -> &'a Stream {
+    status.server.streams.iter().find(|stream| stream.id == id).expect("stream should exist")
+}
+
+#[test]
+fn parses_status_without_stream_properties() {
+    let status = parse(STATUS_0_10);
+
+    assert_eq!(status.server.server.snapserver.version, "0.10.0");
+    assert_eq!(status.server.streams.len(), 2);
+
+    let properties = &stream(&status, "stream 1").properties;
+    assert!(!properties.can_control);
+    assert!(properties.playback_status.is_none());
+    assert!(properties.metadata.is_none());
+}
+
+#[test]
+fn keeps_unknown_query_parameters() {
+    let status = parse(STATUS_0_10);
+
+    let query = &stream(&status, "stream 1").uri.query;
+    assert_eq!(query.codec.as_deref(), Some("flac"));
+    assert!(query.mode.is_none());
+    assert_eq!(query.extra.get("buffer_ms"), Some(&json!("20")));
+}
+
+#[test]
+fn parses_airplay_stream_without_name_or_fragment() {
+    let status = parse(STATUS_0_26);
+
+    let airplay = stream(&status, "AirPlay");
+    assert_eq!(airplay.uri.query.name, "");
+    assert_eq!(airplay.display_name(), "AirPlay");
+    assert_eq!(airplay.uri.fragment, "");
+    assert!(!airplay.properties.can_play);
+    assert!(!StreamCommand::PlayPause.is_supported(&airplay.properties));
+    assert_eq!(airplay.properties.playback_status.as_deref(), Some("playing"));
+}
+
+#[test]
+fn accepts_a_single_artist_string() {
+    let status = parse(STATUS_0_26);
+
+    let metadata = stream(&status, "AirPlay").properties.metadata.as_ref().unwrap();
+    assert_eq!(metadata.artist, vec!["Radiohead"]);
+
+    let metadata = stream(&status, "Spotify").properties.metadata.as_ref().unwrap();
+    assert_eq!(metadata.artist, vec!["Daft Punk"]);
+    assert_eq!(metadata.extra.get("trackId"), Some(&json!("spotify:track:0DiWol3AO6WpXZgp0goxAV")));
+}
+
+#[test]
+fn parses_controllable_stream_properties() {
+    let status = parse(STATUS_0_26);
+
+    let spotify = stream(&status, "Spotify");
+    assert!(spotify.properties.can_control);
+    assert!(StreamCommand::Seek(10.0).is_supported(&spotify.properties));
+    assert_eq!(spotify.properties.position, Some(42.5));
+    assert_eq!(spotify.uri.query.extra.get("bitrate"), Some(&json!("320")));
+}
+
+#[test]
+fn parses_client_without_mac_or_instance() {
+    let status = parse(STATUS_0_28);
+
+    let client = &status.server.groups[0].clients[0];
+    assert_eq!(client.host.mac, "");
+    assert_eq!(client.config.instance, 1);
+    assert_eq!(client.display_name(), "Living room");
+    assert_eq!(client.snapclient.extra.get("features"), Some(&json!(["opus", "pcm"])));
+}
+
+#[test]
+fn keeps_unknown_fields_when_serialized_again() {
+    let status = parse(STATUS_0_28);
+    let value = serde_json::to_value(&status).unwrap();
+
+    assert_eq!(value["server"]["server"]["snapserver"]["ssl"], json!(false));
+    assert_eq!(value["server"]["streams"][0]["properties"]["canStop"], json!(false));
+    assert_eq!(value["server"]["streams"][0]["uri"]["query"]["dryout_ms"], json!("2000"));
+}
+
+#[test]
+fn keeps_unknown_fields_of_nested_objects() {
+    let mut value: Value = serde_json::from_str(STATUS_0_28).unwrap();
+    value["server"]["server"]["uptime"] = json!(86400);
+    value["server"]["streams"][0]["uri"]["port"] = json!(4953);
+    value["server"]["groups"][0]["clients"][0]["config"]["volume"]["balance"] = json!(-10);
+    value["server"]["groups"][0]["clients"][0]["lastSeen"]["tz"] = json!("UTC");
+
+    let status: SnapcastStatus = serde_json::from_value(value).expect("status should parse");
+    let value = serde_json::to_value(&status).unwrap();
+
+    assert_eq!(value["server"]["server"]["uptime"], json!(86400));
+    assert_eq!(value["server"]["streams"][0]["uri"]["port"], json!(4953));
+    assert_eq!(value["server"]["groups"][0]["clients"][0]["config"]["volume"]["balance"], json!(-10));
+    assert_eq!(value["server"]["groups"][0]["clients"][0]["lastSeen"]["tz"], json!("UTC"));
+}
+
+#[test]
+fn every_fixture_parses() {
+    for fixture in [STATUS_0_10, STATUS_0_26, STATUS_0_28] {
+        let status = parse(fixture);
+        assert!(!status.server.groups.is_empty());
+        assert!(!status.server.streams.is_empty());
+    }
+}
+
+// Notification frames as snapserver sends them, parsed the way the session reader does
+fn notification(frame: Value) -> Notification {
+    serde_json::from_value(frame).expect("notification should parse")
+}
+
+fn client<'a>(status: &'a SnapcastStatus, id: &str) -> Option<&'a Client> {
+    status.server.groups.iter().flat_map(|group| group.clients.iter()).find(|client| client.id == id)
+}
+
+#[test]
+fn applies_client_volume_changed() {
+    let mut status = parse(STATUS_0_26);
+
+    status.apply(notification(json!({
+        "jsonrpc": "2.0",
+        "method": "Client.OnVolumeChanged",
+        "params": { "id": "dc:a6:32:0f:11:2b", "volume": { "muted": true, "percent": 74 } }
+    })));
+
+    let volume = &client(&status, "dc:a6:32:0f:11:2b").unwrap().config.volume;
+    assert_eq!(volume.percent, 74);
+    assert!(volume.muted);
+}
+
+#[test]
+fn applies_stream_properties() {
+    let mut status = parse(STATUS_0_26);
+
+    status.apply(notification(json!({
+        "jsonrpc": "2.0",
+        "method": "Stream.OnProperties",
+        "params": {
+            "id": "Spotify",
+            "properties": {
+                "canControl": true,
+                "canGoNext": true,
+                "canGoPrevious": true,
+                "canPause": true,
+                "canPlay": true,
+                "canSeek": true,
+                "loopStatus": "track",
+                "metadata": { "artist": ["Daft Punk"], "title": "Aerodynamic", "duration": 212.0 },
+                "playbackStatus": "paused",
+                "position": 3.0,
+                "shuffle": true,
+                "volume": 80
+            }
+        }
+    })));
+
+    let properties = &stream(&status, "Spotify").properties;
+    assert_eq!(properties.playback_status.as_deref(), Some("paused"));
+    assert_eq!(properties.loop_status.as_deref(), Some("track"));
+    assert_eq!(properties.shuffle, Some(true));
+    assert_eq!(properties.volume, Some(80));
+    assert_eq!(properties.metadata.as_ref().unwrap().title.as_deref(), Some("Aerodynamic"));
+    // Other streams are left alone
+    assert_eq!(stream(&status, "AirPlay").properties.playback_status.as_deref(), Some("playing"));
+}
+
+#[test]
+fn applies_server_update() {
+    let mut status = parse(STATUS_0_26);
+    let update: Value = serde_json::from_str(STATUS_0_28).unwrap();
+
+    status.apply(notification(json!({
+        "jsonrpc": "2.0",
+        "method": "Server.OnUpdate",
+        "params": { "server": update["server"] }
+    })));
+
+    assert_eq!(status.server.server.snapserver.version, parse(STATUS_0_28).server.server.snapserver.version);
+    assert!(client(&status, "dc:a6:32:0f:11:2b").is_none());
+    assert_eq!(status.server.groups[0].clients[0].display_name(), "Living room");
+}
+
+#[test]
+fn ignores_connect_of_unknown_client() {
+    let mut status = parse(STATUS_0_26);
+    let mut stranger = serde_json::to_value(&status.server.groups[0].clients[0]).unwrap();
+    stranger["id"] = json!("00:11:22:33:44:55");
+
+    // It gets placed in a group by the Server.OnUpdate that follows
+    status.apply(notification(json!({
+        "jsonrpc": "2.0",
+        "method": "Client.OnConnect",
+        "params": { "id": "00:11:22:33:44:55", "client": stranger }
+    })));
+
+    assert!(client(&status, "00:11:22:33:44:55").is_none());
+    assert_eq!(status.server.groups.iter().map(|group| group.clients.len()).sum::<usize>(), 2);
+}
+
+#[test]
+fn applies_connect_of_known_client() {
+    let mut status = parse(STATUS_0_26);
+    let mut desktop = serde_json::to_value(client(&status, "desktop#2").unwrap()).unwrap();
+    desktop["connected"] = json!(true);
+
+    status.apply(notification(json!({
+        "jsonrpc": "2.0",
+        "method": "Client.OnConnect",
+        "params": { "id": "desktop#2", "client": desktop }
+    })));
+
+    assert!(client(&status, "desktop#2").unwrap().connected);
+}
+
+#[test]
+fn retries_only_failures_the_network_can_fix() {
+    let refused = io::Error::from(io::ErrorKind::ConnectionRefused);
+    assert!(SnapcastError::Connect(tungstenite::Error::Io(refused)).is_retryable());
+    assert!(SnapcastError::Connect(tungstenite::Error::ConnectionClosed).is_retryable());
+    assert!(SnapcastError::Closed.is_retryable());
+    assert!(SnapcastError::Timeout { method: "Server.GetStatus".to_string() }.is_retryable());
+
+    let tls = tungstenite::Error::Url(tungstenite::error::UrlError::TlsFeatureNotEnabled);
+    assert!(!SnapcastError::Connect(tls).is_retryable());
+    let not_found = tungstenite::http::Response::builder().status(404).body(None).unwrap();
+    assert!(!SnapcastError::Connect(tungstenite::Error::Http(not_found)).is_retryable());
+    assert!(!SnapcastError::Invalid("Unknown client".to_string()).is_retryable());
+}
+
+// The Downstairs group of the 0.26 fixture with two more members: a connected
+// one at 20% and a disconnected one at 90%, next to the kitchen at 48%
+fn mixed_group() -> Group {
+    let status = parse(STATUS_0_26);
+    let mut group = status.server.groups[0].clone();
+    let kitchen = group.clients[0].clone();
+
+    let mut study = kitchen.clone();
+    study.id = "study".to_string();
+    study.config.volume.percent = 20;
+    let mut garage = kitchen;
+    garage.id = "garage".to_string();
+    garage.config.volume.percent = 90;
+    garage.connected = false;
+
+    group.clients.push(study);
+    group.clients.push(garage);
+    group
+}
+
+#[test]
+fn group_volume_averages_the_connected_clients() {
+    assert_eq!(mixed_group().volume(), Some(34));
+
+    let mut group = mixed_group();
+    group.clients.iter_mut().for_each(|client| client.connected = false);
+    assert_eq!(group.volume(), None);
+    assert!(group.scaled_volumes(50).is_empty());
+}
+
+#[test]
+fn scales_group_volume_down_towards_zero() {
+    let group = mixed_group();
+
+    let halved = group.scaled_volumes(17);
+    assert_eq!(halved, vec![("dc:a6:32:0f:11:2b".to_string(), 24), ("study".to_string(), 10)]);
+    assert!(group.scaled_volumes(0).iter().all(|(_, volume)| *volume == 0));
+}
+
+#[test]
+fn scales_group_volume_up_towards_a_hundred() {
+    let group = mixed_group();
+
+    let halfway = group.scaled_volumes(67);
+    assert_eq!(halfway, vec![("dc:a6:32:0f:11:2b".to_string(), 74), ("study".to_string(), 60)]);
+    assert!(group.scaled_volumes(100).iter().all(|(_, volume)| *volume == 100));
+    // Out of range asks are capped
+    assert_eq!(group.scaled_volumes(250), group.scaled_volumes(100));
+}
+
+#[test]
+fn scales_a_silent_group_up_evenly() {
+    let mut group = mixed_group();
+    group.clients.iter_mut().for_each(|client| client.config.volume.percent = 0);
+
+    assert!(group.scaled_volumes(30).iter().all(|(_, volume)| *volume == 30));
+}
+
+#[test]
+fn finds_disconnected_clients_not_seen_for_a_while() {
+    let status = parse(STATUS_0_26);
+
+    // desktop#2 went away in November 2023, the kitchen is still connected
+    let stale: Vec<&str> = status.stale_clients_for_days(30).iter().map(|client| client.id.as_str()).collect();
+    assert_eq!(stale, vec!["desktop#2"]);
+    assert!(status.stale_clients_for_days(365 * 1000).is_empty());
+}
+
+#[test]
+fn saturates_huge_purge_ages() {
+    let status = parse(STATUS_0_26);
+
+    assert!(status.stale_clients_for_days(u64::MAX).is_empty());
+    assert!(status.stale_clients(Duration::MAX).is_empty());
+}
+
+// The 0.26 fixture with Spotify's position last reported five seconds ago
+fn playing_for_a_while() -> SnapcastStatus {
+    let mut status = parse(STATUS_0_26);
+    let properties = &mut status.stream_mut("Spotify").unwrap().properties;
+    properties.received_at = Instant::now() - Duration::from_secs(5);
+    status
+}
+
+#[test]
+fn keeps_the_position_clock_through_a_full_update() {
+    let mut status = playing_for_a_while();
+    let update = parse(STATUS_0_26).server;
+
+    status.apply(Notification::ServerUpdate { server: update });
+
+    // Still 42.5 reported five seconds ago, not 42.5 reported just now
+    let position = stream(&status, "Spotify").properties.current_position().unwrap();
+    assert!(position >= 47.5, "position went back to {}", position);
+}
+
+#[test]
+fn takes_a_newer_position_or_a_new_track() {
+    let mut status = playing_for_a_while();
+    let mut update = parse(STATUS_0_26);
+    update.stream_mut("Spotify").unwrap().properties.position = Some(10.0);
+    status.apply(Notification::ServerUpdate { server: update.server });
+    assert!(stream(&status, "Spotify").properties.current_position().unwrap() < 11.0);
+
+    let mut status = playing_for_a_while();
+    let mut update = parse(STATUS_0_26);
+    let metadata = update.stream_mut("Spotify").unwrap().properties.metadata.as_mut().unwrap();
+    metadata.title = Some("Aerodynamic".to_string());
+    status.apply(Notification::ServerUpdate { server: update.server });
+    assert!(stream(&status, "Spotify").properties.current_position().unwrap() < 43.5);
+}
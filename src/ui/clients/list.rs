@@ -18,7 +18,7 @@ pub fn draw_clients_list(f: &mut Frame, area: Rect, app: &App) {
     };
 
     // Calculate clients count for title
-    let clients_count = if let Some(status) = &app.snapcast_client.status {
+    let clients_count = if let Some(status) = &app.network.status {
         status.server.groups.iter()
             .flat_map(|group| &group.clients)
             .count()
@@ -40,33 +40,36 @@ pub fn draw_clients_list(f: &mut Frame, area: Rect, app: &App) {
         .alignment(Alignment::Center)
         .block(Block::default().borders(Borders::NONE));
 
-    if let Some(status) = &app.snapcast_client.status {
+    if let Some(status) = &app.network.status {
         // Collect all clients from all groups
         let all_clients: Vec<_> = status.server.groups
             .iter()
             .flat_map(|group| group.clients.iter())
             .collect();
 
-        let mut list_state = ListState::default();
-        list_state.select(app.selected_item);
+        let selected = app.selected_index();
+        // Start from where this tab's list was scrolled to last time
+        let mut list_state = ListState::default()
+            .with_offset(app.selection.offset(1))
+            .with_selected(selected);
 
         let items: Vec<ListItem> = all_clients
             .iter()
             .enumerate()
             .map(|(idx, client)| {
-                let prefix = if Some(idx) == app.selected_item {
+                let prefix = if Some(idx) == selected {
                     "> "  // Selection indicator
                 } else {
                     "  "  // Regular indentation
                 };
 
-                // Format client information - just showing the client ID
-                let client_info = format!("{}{}", prefix, client.id);
+                // Format client information - friendly name, hostname or ID
+                let client_info = format!("{}{}", prefix, client.display_name());
 
                 let content = Line::from(vec![
                     Span::styled(
                         client_info,
-                        if Some(idx) == app.selected_item {
+                        if Some(idx) == selected {
                             Style::default()
                                 .fg(Color::Green)
                                 .add_modifier(Modifier::BOLD)
@@ -86,6 +89,7 @@ pub fn draw_clients_list(f: &mut Frame, area: Rect, app: &App) {
         // Render the centered title above the list
         f.render_widget(title_paragraph, list_area);
         f.render_stateful_widget(list, inner_list_area, &mut list_state);
+        app.selection.set_offset(1, list_state.offset());
     } else {
         let content = Paragraph::new("No data available. Press 'r' to refresh.")
             .style(Style::default().fg(Color::White))
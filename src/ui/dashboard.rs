@@ -0,0 +1,147 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+use crate::App;
+use crate::network::Network;
+use crate::ui::key_hints;
+
+// Height of one server panel, borders included
+const PANEL_HEIGHT: u16 = 7;
+
+pub fn draw_dashboard(f: &mut Frame, area: Rect, app: &App) {
+    // Panels on top, key hints on the last row
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(1),     // Server panels
+            Constraint::Length(1),  // Key hints
+        ])
+        .split(area);
+
+    // Scroll just far enough to keep the panel under the cursor in view
+    let visible = (layout[0].height / PANEL_HEIGHT).max(1) as usize;
+    let first = app.dashboard_cursor.saturating_sub(visible - 1);
+
+    for (row, index) in (first..app.servers.len()).take(visible).enumerate() {
+        let panel_area = Rect {
+            x: layout[0].x,
+            y: layout[0].y + row as u16 * PANEL_HEIGHT,
+            width: layout[0].width,
+            height: PANEL_HEIGHT.min(layout[0].height),
+        };
+        draw_panel(f, panel_area, app, index);
+    }
+
+    let hints = Paragraph::new(key_hints(&[("↑↓", "select"), ("enter", "open")]))
+        .alignment(Alignment::Center);
+    f.render_widget(hints, layout[1]);
+}
+
+fn draw_panel(f: &mut Frame, area: Rect, app: &App, index: usize) {
+    let server = &app.servers[index];
+    let is_selected = index == app.dashboard_cursor;
+
+    let mut title = vec![Span::styled(
+        format!(" {} ", server.label),
+        Style::default()
+            .fg(if is_selected { Color::Green } else { Color::White })
+            .add_modifier(Modifier::BOLD),
+    )];
+    if index == app.active_server {
+        title.push(Span::styled("(active) ", Style::default().fg(Color::Cyan)));
+    }
+
+    let block = Block::default()
+        .title(Line::from(title))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(if is_selected { Color::Green } else { Color::White }));
+
+    let inner_area = Rect {
+        x: area.x + 2,
+        y: area.y + 1,
+        width: area.width.saturating_sub(4),
+        height: area.height.saturating_sub(2),
+    };
+    f.render_widget(block, area);
+
+    let Some((network, connection_error)) = app.session(index) else {
+        return;
+    };
+
+    let mut lines = Vec::new();
+    lines.push(Line::from(vec![
+        connection_state(network, connection_error),
+        Span::styled(format!("  {}", server.url), Style::default().fg(Color::Gray)),
+    ]));
+    if let Some(failure) = app.session_failure(index) {
+        lines.push(Line::from(Span::styled(failure.to_string(), Style::default().fg(Color::Red))));
+    }
+
+    let Some(status) = &network.status else {
+        if let Some(error) = connection_error {
+            lines.push(Line::from(Span::styled(error.to_string(), Style::default().fg(Color::White))));
+        }
+        f.render_widget(Paragraph::new(lines), inner_area);
+        return;
+    };
+
+    let clients: Vec<_> = status.server.groups.iter()
+        .flat_map(|group| group.clients.iter())
+        .collect();
+    let connected = clients.iter().filter(|client| client.connected).count();
+
+    lines.push(Line::from(vec![
+        Span::styled("Groups: ", Style::default().fg(Color::Yellow)),
+        Span::styled(status.server.groups.len().to_string(), Style::default().fg(Color::White)),
+        Span::styled("  Clients: ", Style::default().fg(Color::Yellow)),
+        Span::styled(clients.len().to_string(), Style::default().fg(Color::White)),
+        Span::styled(format!(" ({} connected)", connected), Style::default().fg(Color::Gray)),
+    ]));
+
+    // Streams some group is listening to, with whatever they're playing
+    let playing: Vec<_> = status.server.streams.iter()
+        .filter(|stream| stream.status == "playing")
+        .filter(|stream| status.server.groups.iter().any(|group| group.stream_id == stream.id))
+        .collect();
+
+    if playing.is_empty() {
+        lines.push(Line::from(Span::styled("Nothing playing", Style::default().fg(Color::DarkGray))));
+    }
+
+    let room = (inner_area.height as usize).saturating_sub(lines.len());
+    for (idx, stream) in playing.iter().enumerate() {
+        // Leave the last line to say how many didn't fit
+        if idx + 1 == room && playing.len() > room {
+            lines.push(Line::from(Span::styled(
+                format!("  and {} more", playing.len() - idx),
+                Style::default().fg(Color::Gray),
+            )));
+            break;
+        }
+
+        let mut spans = vec![
+            Span::styled("▶ ", Style::default().fg(Color::Green)),
+            Span::styled(stream.display_name().to_string(), Style::default().fg(Color::Blue)),
+        ];
+        if let Some(summary) = stream.now_playing_summary() {
+            spans.push(Span::styled(format!("  {}", summary), Style::default().fg(Color::Gray)));
+        }
+        lines.push(Line::from(spans));
+    }
+
+    f.render_widget(Paragraph::new(lines), inner_area);
+}
+
+fn connection_state(network: &Network, connection_error: Option<&str>) -> Span<'static> {
+    if connection_error.is_some() {
+        Span::styled("Disconnected", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD))
+    } else if network.status.is_some() {
+        Span::styled("Connected", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD))
+    } else {
+        Span::styled("Connecting...", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+    }
+}
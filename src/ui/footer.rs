@@ -1,5 +1,5 @@
 use ratatui::{
-    layout::{Rect, Margin},
+    layout::Rect,
     style::{Color, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
@@ -37,7 +37,7 @@ pub fn draw_footer(f: &mut Frame, area: Rect, app: &App) {
         // Get connection status
         let status_text = if app.connection_error.is_some() {
             Span::styled("Disconnected", Style::default().fg(Color::Red))
-        } else if app.snapcast_client.status.is_some() {
+        } else if app.network.status.is_some() {
             Span::styled("Connected", Style::default().fg(Color::Green))
         } else {
             Span::styled("Connecting...", Style::default().fg(Color::Yellow))
@@ -56,12 +56,14 @@ pub fn draw_footer(f: &mut Frame, area: Rect, app: &App) {
                 Span::styled("'r'", Style::default().fg(Color::Yellow)),
                 Span::styled(" to refresh | ", Style::default().fg(Color::Magenta)),
                 Span::styled("←→", Style::default().fg(Color::Yellow)),
-                Span::styled(" to switch tabs", Style::default().fg(Color::Magenta)),
+                Span::styled(" to switch tabs | ", Style::default().fg(Color::Magenta)),
+                Span::styled("'S'", Style::default().fg(Color::Yellow)),
+                Span::styled(" to switch server", Style::default().fg(Color::Magenta)),
             ]),
             Line::from(vec![
                 Span::styled("Connected to: ", Style::default().fg(Color::Magenta)),
                 Span::styled(
-                    format!("{}", app.snapcast_client.get_url()),
+                    app.network.get_url(),
                     Style::default().fg(Color::White)
                 ),
             ]),
@@ -6,7 +6,10 @@ use ratatui::{
     Frame,
 };
 use crate::App;
+use crate::ui::key_hints;
 
+// Built line by line, one push per field
+#[allow(clippy::vec_init_then_push)]
 pub fn draw_group_details(f: &mut Frame, area: Rect, app: &App) {
     // Create a block with a title for the group details
     let block = Block::default()
@@ -16,72 +19,96 @@ pub fn draw_group_details(f: &mut Frame, area: Rect, app: &App) {
     // Render the block
     f.render_widget(block, area);
 
-    // Create an inner area with margins
+    // Create an inner area with margins, keeping the last row for key hints
     let inner_area = Rect {
         x: area.x + 4,
         y: area.y + 2,
-        width: area.width.saturating_sub(2),
-        height: area.height.saturating_sub(2),
+        width: area.width.saturating_sub(5),
+        height: area.height.saturating_sub(4),
     };
 
     // Check if we have data and a selected item
-    if let Some(status) = &app.snapcast_client.status {
-        if let Some(selected_idx) = app.selected_item {
-            if let Some(group) = status.server.groups.get(selected_idx) {
-                let mut details = Vec::new();
+    if app.network.status.is_some() {
+        if let Some(group) = app.selected_group() {
+            let mut details = Vec::new();
 
-                details.push(Line::from(vec![
-                    Span::styled("Id: ", Style::default().fg(Color::Yellow)),
-                    Span::styled(group.id.to_string(), Style::default().fg(Color::White)),
-                ]));
+            details.push(Line::from(vec![
+                Span::styled("Id: ", Style::default().fg(Color::Yellow)),
+                Span::styled(group.id.to_string(), Style::default().fg(Color::White)),
+            ]));
 
-                details.push(Line::from(vec![
-                    Span::styled("Name: ", Style::default().fg(Color::Yellow)),
-                    Span::styled(group.name.to_string(), Style::default().fg(Color::White)),
-                ]));
+            details.push(Line::from(vec![
+                Span::styled("Name: ", Style::default().fg(Color::Yellow)),
+                Span::styled(group.name.to_string(), Style::default().fg(Color::White)),
+            ]));
 
-                details.push(Line::from(vec![
-                    Span::styled("Stream Id: ", Style::default().fg(Color::Yellow)),
-                    Span::styled(group.stream_id.to_string(), Style::default().fg(Color::White)),
-                ]));
+            details.push(Line::from(vec![
+                Span::styled("Stream Id: ", Style::default().fg(Color::Yellow)),
+                Span::styled(group.stream_id.to_string(), Style::default().fg(Color::White)),
+            ]));
 
-                details.push(Line::from(vec![
-                    Span::styled("Muted: ", Style::default().fg(Color::Yellow)),
-                    Span::styled(group.muted.to_string(), Style::default().fg(Color::White)),
-                ]));
+            details.push(Line::from(vec![
+                Span::styled("Muted: ", Style::default().fg(Color::Yellow)),
+                Span::styled(group.muted.to_string(), Style::default().fg(Color::White)),
+            ]));
+
+            details.push(Line::from(vec![
+                Span::styled("Volume: ", Style::default().fg(Color::Yellow)),
+                Span::styled(
+                    group.volume().map(|volume| volume.to_string()).unwrap_or_else(|| "-".to_string()),
+                    Style::default().fg(Color::White)
+                ),
+            ]));
 
+            details.push(Line::from(vec![
+                Span::styled("Clients: ", Style::default().fg(Color::Yellow)),
+            ]));
+
+            // Add each client's ID
+            if group.clients.is_empty() {
                 details.push(Line::from(vec![
-                    Span::styled("Clients: ", Style::default().fg(Color::Yellow)),
+                    Span::styled("  No clients connected", Style::default().fg(Color::Gray)),
                 ]));
+            } else {
+                for client in &group.clients {
+                    details.push(Line::from(vec![
+                        Span::styled("  - Id: ", Style::default().fg(Color::Blue)),
+                        Span::styled(client.id.to_string(), Style::default().fg(Color::White)),
+                    ]));
 
-                // Add each client's ID
-                if group.clients.is_empty() {
                     details.push(Line::from(vec![
-                        Span::styled("  No clients connected", Style::default().fg(Color::Gray)),
+                        Span::styled("    Connected: ", Style::default().fg(Color::Blue)),
+                        Span::styled(client.connected.to_string(), Style::default().fg(Color::White)),
                     ]));
-                } else {
-                    for client in &group.clients {
-                        details.push(Line::from(vec![
-                            Span::styled("  - Id: ", Style::default().fg(Color::Blue)),
-                            Span::styled(client.id.to_string(), Style::default().fg(Color::White)),
-                        ]));
-
-                        details.push(Line::from(vec![
-                            Span::styled("    Connected: ", Style::default().fg(Color::Blue)),
-                            Span::styled(client.connected.to_string(), Style::default().fg(Color::White)),
-                        ]));
-                    }
                 }
+            }
+
+            // Create the paragraph with all the details
+            let details = Paragraph::new(details);
 
-                // Create the paragraph with all the details
-                let details = Paragraph::new(details);
+            // Render the details
+            f.render_widget(details, inner_area);
 
-                // Render the details
-                f.render_widget(details, inner_area);
-                return;
+            // Show the group actions on the last line of the panel
+            let hints_area = Rect {
+                x: inner_area.x,
+                y: area.y + area.height.saturating_sub(2),
+                width: inner_area.width,
+                height: 1,
+            };
+            let hints = Paragraph::new(key_hints(&[
+                ("enter", "fold"),
+                ("t", "stream"),
+                ("m", "mute"),
+                ("+/-", "volume"),
+                ("v", "set volume"),
+                ("o", "move client"),
+                ("n", "rename"),
+            ]));
+            f.render_widget(hints, hints_area);
+            return;
         }
-    }
-    // If we have data but nothing is selected, show an empty block
+        // If we have data but nothing is selected, show an empty block
         let empty_block = Block::default().borders(Borders::NONE);
         f.render_widget(empty_block, inner_area);
     } else {
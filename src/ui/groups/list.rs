@@ -6,6 +6,12 @@ use ratatui::{
     widgets::{Block, Borders, Paragraph, List, ListItem, ListState}
 };
 use crate::App;
+use crate::selection::TreeRow;
+use crate::snapcast::Group;
+use crate::ui::playback_symbol;
+
+// Width of the volume bar next to each client
+const VOLUME_BAR_WIDTH: usize = 10;
 
 pub fn draw_groups_list(f: &mut Frame, area: Rect, app: &App) {
     let list_area = area;
@@ -17,7 +23,7 @@ pub fn draw_groups_list(f: &mut Frame, area: Rect, app: &App) {
     };
 
     // Calculate groups count for title
-    let groups_count = if let Some(status) = &app.snapcast_client.status {
+    let groups_count = if let Some(status) = &app.network.status {
         status.server.groups.len()
     } else {
         0
@@ -37,32 +43,80 @@ pub fn draw_groups_list(f: &mut Frame, area: Rect, app: &App) {
         .alignment(Alignment::Center)
         .block(Block::default().borders(Borders::NONE));
 
-    if let Some(status) = &app.snapcast_client.status {
-        let mut list_state = ListState::default();
-        list_state.select(app.selected_item);
+    if let Some(status) = &app.network.status {
+        let selected = app.selected_index();
+        // Start from where this tab's list was scrolled to last time
+        let mut list_state = ListState::default()
+            .with_offset(app.selection.offset(0))
+            .with_selected(selected);
 
-        let items: Vec<ListItem> = status.server.groups
-            .iter()
+        let items: Vec<ListItem> = app.selection.group_tree(status)
+            .into_iter()
             .enumerate()
-            .map(|(idx, group)| {
-                let prefix = if Some(idx) == app.selected_item {
+            .map(|(idx, row)| {
+                let prefix = if Some(idx) == selected {
                     "> "  // Selection indicator
                 } else {
                     "  "  // Regular indentation
                 };
+                let name_style = if Some(idx) == selected {
+                    Style::default()
+                        .fg(Color::Green)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(Color::White)
+                };
 
-                let content = Line::from(vec![
-                    Span::styled(
-                        format!("{}{}", prefix, group.id),
-                        if Some(idx) == app.selected_item {
-                            Style::default()
-                                .fg(Color::Green)
-                                .add_modifier(Modifier::BOLD)
-                        } else {
-                            Style::default().fg(Color::White)
+                let content = match row {
+                    TreeRow::Group(group) => {
+                        let expander = if app.selection.is_expanded(&group.id) { "▾" } else { "▸" };
+                        let stream = status.server.streams
+                            .iter()
+                            .find(|stream| stream.id == group.stream_id);
+
+                        let mut spans = vec![
+                            Span::styled(format!("{}{} {}", prefix, expander, group_label(group)), name_style),
+                            Span::styled(
+                                format!("  {}", stream.map(|stream| stream.display_name()).unwrap_or(&group.stream_id)),
+                                Style::default().fg(Color::Blue),
+                            ),
+                        ];
+                        if group.muted {
+                            spans.push(Span::styled("  muted", Style::default().fg(Color::Red)));
+                        }
+
+                        // What the group's stream is playing, if anything
+                        if let Some(stream) = stream
+                            && let Some(summary) = stream.now_playing_summary()
+                        {
+                            spans.push(Span::styled(
+                                format!("  {} {}", playback_symbol(stream.properties.playback_status.as_deref()), summary),
+                                Style::default().fg(Color::Gray),
+                            ));
                         }
-                    ),
-                ]);
+                        Line::from(spans)
+                    }
+                    TreeRow::Client(_, client) => {
+                        let (indicator, indicator_color) = if client.connected {
+                            ("●", Color::Green)
+                        } else {
+                            ("○", Color::DarkGray)
+                        };
+                        let volume = &client.config.volume;
+                        let bar_color = if volume.muted || !client.connected { Color::DarkGray } else { Color::Yellow };
+
+                        Line::from(vec![
+                            Span::styled(format!("{}  ", prefix), name_style),
+                            Span::styled(format!("{} ", indicator), Style::default().fg(indicator_color)),
+                            Span::styled(client.display_name().to_string(), name_style),
+                            Span::styled(format!("  {}", volume_bar(volume.percent)), Style::default().fg(bar_color)),
+                            Span::styled(
+                                if volume.muted { " muted".to_string() } else { format!(" {}%", volume.percent) },
+                                Style::default().fg(if volume.muted { Color::Red } else { Color::Gray }),
+                            ),
+                        ])
+                    }
+                };
 
                 ListItem::new(content)
             })
@@ -74,6 +128,7 @@ pub fn draw_groups_list(f: &mut Frame, area: Rect, app: &App) {
         // Render the centered title above the list
         f.render_widget(title_paragraph, list_area);
         f.render_stateful_widget(list, inner_list_area, &mut list_state);
+        app.selection.set_offset(0, list_state.offset());
     } else {
         let content = Paragraph::new("No data available. Press 'r' to refresh.")
             .style(Style::default().fg(Color::White))
@@ -83,4 +138,20 @@ pub fn draw_groups_list(f: &mut Frame, area: Rect, app: &App) {
         f.render_widget(title_paragraph, list_area);
         f.render_widget(content, inner_list_area);
     }
-}
\ No newline at end of file
+}
+
+// Volume as a bar of filled and empty cells
+fn volume_bar(percent: u32) -> String {
+    let filled = (percent.min(100) as usize * VOLUME_BAR_WIDTH + 50) / 100;
+    format!("{}{}", "█".repeat(filled), "░".repeat(VOLUME_BAR_WIDTH - filled))
+}
+
+// Unnamed groups are shown by their members rather than their UUID
+fn group_label(group: &Group) -> String {
+    if group.name.is_empty() && !group.clients.is_empty() {
+        let members: Vec<&str> = group.clients.iter().map(|client| client.display_name()).collect();
+        format!("[{}]", members.join(", "))
+    } else {
+        group.display_name().to_string()
+    }
+}
@@ -3,6 +3,8 @@ mod details;
 
 use ratatui::Frame;
 use crate::App;
+use crate::selection::TreeRow;
+use crate::ui::{draw_client_details, draw_now_playing};
 
 pub use list::draw_groups_list;
 pub use details::draw_group_details;
@@ -20,6 +22,25 @@ pub fn draw_groups(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
     // Draw the groups list
     draw_groups_list(f, content_layout[0], app);
 
-    // Draw the group details
-    draw_group_details(f, content_layout[1], app);
+    // Split the right side between what the group plays and its details
+    let details_layout = ratatui::layout::Layout::default()
+        .direction(ratatui::layout::Direction::Vertical)
+        .constraints([
+            ratatui::layout::Constraint::Length(6),  // Now playing
+            ratatui::layout::Constraint::Min(1),     // Group details
+        ])
+        .split(content_layout[1]);
+
+    // Draw what the selected group, or the selected client's group, is playing
+    let row = app.selected_tree_row();
+    let stream = app.network.status.as_ref().zip(row).and_then(|(status, row)| {
+        status.server.streams.iter().find(|stream| stream.id == row.group().stream_id)
+    });
+    draw_now_playing(f, details_layout[0], stream);
+
+    // Draw the details of whatever row of the tree is selected
+    match row {
+        Some(TreeRow::Client(..)) => draw_client_details(f, details_layout[1], app),
+        _ => draw_group_details(f, details_layout[1], app),
+    }
 }
\ No newline at end of file
@@ -6,6 +6,10 @@ use ratatui::{
     widgets::{Paragraph, Block, Borders}
 };
 use crate::App;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// Braille frames for the busy indicator, one per 100ms redraw
+const SPINNER: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
 
 pub fn draw_header(f: &mut Frame, area: Rect, app: &App) {
     // Create a centered title with connection status
@@ -18,7 +22,7 @@ pub fn draw_header(f: &mut Frame, area: Rect, app: &App) {
                 .fg(Color::Red)
                 .add_modifier(Modifier::BOLD)
         )
-    } else if app.snapcast_client.status.is_some() {
+    } else if app.network.status.is_some() {
         Span::styled(
             "Connected",
             Style::default()
@@ -34,7 +38,7 @@ pub fn draw_header(f: &mut Frame, area: Rect, app: &App) {
         )
     };
 
-    let title_line = Line::from(vec![
+    let mut title_line = Line::from(vec![
         Span::styled(
             title,
             Style::default()
@@ -42,9 +46,22 @@ pub fn draw_header(f: &mut Frame, area: Rect, app: &App) {
                 .add_modifier(Modifier::BOLD)
         ),
         Span::styled(" | ", Style::default().fg(Color::White)),
+        Span::styled(
+            app.active_server().map(|server| server.label.as_str()).unwrap_or_default(),
+            Style::default().fg(Color::Cyan)
+        ),
+        Span::styled(" | ", Style::default().fg(Color::White)),
         status
     ]);
 
+    // Spin while the network task is still working on a request
+    if app.network.is_busy() {
+        title_line.spans.push(Span::styled(
+            format!(" {}", spinner_frame()),
+            Style::default().fg(Color::Yellow),
+        ));
+    }
+
     let title_paragraph = Paragraph::new(title_line)
         .alignment(Alignment::Center)
         .block(Block::default().borders(Borders::NONE));
@@ -59,7 +76,7 @@ pub fn draw_header(f: &mut Frame, area: Rect, app: &App) {
         height: area.height - 1,
     };
 
-    if let Some(status) = &app.snapcast_client.status {
+    if let Some(status) = &app.network.status {
         let server = &status.server.server.snapserver;
         let server_info = Paragraph::new(vec![
             Line::from(vec![
@@ -96,4 +113,9 @@ pub fn draw_header(f: &mut Frame, area: Rect, app: &App) {
 
         f.render_widget(connecting_message, info_area);
     }
-}
\ No newline at end of file
+}
+
+fn spinner_frame() -> &'static str {
+    let millis = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis();
+    SPINNER[(millis / 100) as usize % SPINNER.len()]
+}
@@ -0,0 +1,30 @@
+use ratatui::{
+    style::{Color, Style},
+    text::{Line, Span},
+};
+
+// One line summary of the keys available in a panel, e.g. `'m' mute | 'v' volume`
+pub fn key_hints(keys: &[(&str, &str)]) -> Line<'static> {
+    let keys: Vec<_> = keys.iter().map(|(key, action)| (*key, *action, true)).collect();
+    key_hints_enabled(&keys)
+}
+
+// Same as `key_hints`, with disabled keys greyed out
+pub fn key_hints_enabled(keys: &[(&str, &str, bool)]) -> Line<'static> {
+    let mut spans = Vec::new();
+
+    for (idx, (key, action, enabled)) in keys.iter().enumerate() {
+        if idx > 0 {
+            spans.push(Span::styled(" | ", Style::default().fg(Color::Magenta)));
+        }
+        let (key_color, action_color) = if *enabled {
+            (Color::Yellow, Color::Magenta)
+        } else {
+            (Color::DarkGray, Color::DarkGray)
+        };
+        spans.push(Span::styled(format!("'{}'", key), Style::default().fg(key_color)));
+        spans.push(Span::styled(format!(" {}", action), Style::default().fg(action_color)));
+    }
+
+    Line::from(spans)
+}
@@ -4,6 +4,11 @@ mod streams;
 mod clients;
 mod groups;
 mod footer; // Add this line
+mod hints;
+mod now_playing;
+mod popup;
+mod server;
+mod dashboard;
 
 use ratatui::Frame;
 use crate::App;
@@ -11,9 +16,14 @@ use crate::App;
 pub use header::draw_header;
 pub use tabs::draw_tabs;
 pub use streams::draw_streams;
-pub use clients::draw_clients;
+pub use clients::{draw_client_details, draw_clients};
 pub use groups::draw_groups;
 pub use footer::draw_footer; // Add this line
+pub use hints::{key_hints, key_hints_enabled};
+pub use now_playing::{draw_now_playing, format_time, playback_symbol};
+pub use popup::draw_popup;
+pub use server::draw_server;
+pub use dashboard::draw_dashboard;
 
 pub fn ui(f: &mut Frame, app: &App) {
     // Main vertical layout
@@ -38,9 +48,14 @@ pub fn ui(f: &mut Frame, app: &App) {
         0 => draw_groups(f, main_layout[2], app),
         1 => draw_clients(f, main_layout[2], app),
         2 => draw_streams(f, main_layout[2], app),
+        3 => draw_server(f, main_layout[2], app),
+        4 => draw_dashboard(f, main_layout[2], app),
         _ => draw_groups(f, main_layout[2], app),
     }
 
     // Draw footer with error messages
     draw_footer(f, main_layout[3], app);
+
+    // Draw any open popup on top of everything else
+    draw_popup(f, app);
 }
\ No newline at end of file
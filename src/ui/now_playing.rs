@@ -0,0 +1,126 @@
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    symbols,
+    text::{Line, Span},
+    widgets::{Block, Borders, LineGauge, Paragraph},
+    Frame,
+};
+use crate::snapcast::{Stream, StreamProperties};
+
+pub fn draw_now_playing(f: &mut Frame, area: Rect, stream: Option<&Stream>) {
+    let block = Block::default()
+        .title(" Now Playing ")
+        .borders(Borders::ALL);
+    let inner_area = block.inner(area);
+    f.render_widget(block, area);
+
+    let inner_area = Rect {
+        x: inner_area.x + 1,
+        width: inner_area.width.saturating_sub(2),
+        ..inner_area
+    };
+
+    let Some(stream) = stream else {
+        let paragraph = Paragraph::new("No stream selected")
+            .style(Style::default().fg(Color::Gray));
+        f.render_widget(paragraph, inner_area);
+        return;
+    };
+
+    let properties = &stream.properties;
+    let metadata = properties.metadata.as_ref();
+
+    let title = metadata
+        .and_then(|metadata| metadata.title.clone())
+        .unwrap_or_else(|| "Nothing playing".to_string());
+    let artist = metadata.map(|metadata| metadata.artist.join(", ")).unwrap_or_default();
+    let album = metadata.and_then(|metadata| metadata.album.clone()).unwrap_or_default();
+
+    let lines = vec![
+        Line::from(vec![
+            Span::styled(
+                format!("{} ", playback_symbol(properties.playback_status.as_deref())),
+                Style::default().fg(Color::Green),
+            ),
+            Span::styled(title, Style::default().fg(Color::White).add_modifier(Modifier::BOLD)),
+        ]),
+        Line::from(vec![
+            Span::styled(format!("  {}", artist), Style::default().fg(Color::Yellow)),
+            Span::styled(
+                if album.is_empty() { String::new() } else { format!(" - {}", album) },
+                Style::default().fg(Color::Gray),
+            ),
+        ]),
+        playback_state_line(properties),
+    ];
+    f.render_widget(Paragraph::new(lines), inner_area);
+
+    // Progress bar on the last line, only when the track length is known
+    let duration = metadata.and_then(|metadata| metadata.duration);
+    if let (Some(position), Some(duration)) = (properties.current_position(), duration)
+        && duration > 0.0
+        && inner_area.height >= 4
+    {
+        let progress_area = Rect {
+            y: inner_area.y + inner_area.height - 1,
+            height: 1,
+            ..inner_area
+        };
+        let gauge = LineGauge::default()
+            .ratio((position / duration).clamp(0.0, 1.0))
+            .label(format!("{} / {}", format_time(position), format_time(duration)))
+            .line_set(symbols::line::THICK)
+            .gauge_style(Style::default().fg(Color::Green).bg(Color::DarkGray));
+        f.render_widget(gauge, progress_area);
+    }
+}
+
+// Loop, shuffle, volume and rate as last confirmed by the server
+fn playback_state_line(properties: &StreamProperties) -> Line<'static> {
+    let mut spans = vec![Span::raw("  ")];
+    let mut push = |label: &str, value: String| {
+        if spans.len() > 1 {
+            spans.push(Span::styled(" | ", Style::default().fg(Color::Gray)));
+        }
+        spans.push(Span::styled(format!("{}: ", label), Style::default().fg(Color::Blue)));
+        spans.push(Span::styled(value, Style::default().fg(Color::White)));
+    };
+
+    if let Some(loop_status) = &properties.loop_status {
+        push("Loop", loop_status.clone());
+    }
+    if let Some(shuffle) = properties.shuffle {
+        push("Shuffle", if shuffle { "on" } else { "off" }.to_string());
+    }
+    if let Some(volume) = properties.volume {
+        let muted = if properties.mute == Some(true) { " (muted)" } else { "" };
+        push("Volume", format!("{}{}", volume, muted));
+    }
+    if let Some(rate) = properties.rate {
+        push("Rate", format!("{:.1}x", rate));
+    }
+
+    Line::from(spans)
+}
+
+pub fn playback_symbol(playback_status: Option<&str>) -> &'static str {
+    match playback_status {
+        Some("playing") => "▶",
+        Some("paused") => "⏸",
+        Some("stopped") => "■",
+        _ => "·",
+    }
+}
+
+// Seconds as m:ss, or h:mm:ss for long tracks
+pub fn format_time(seconds: f64) -> String {
+    let total = seconds.max(0.0) as u64;
+    let (hours, minutes, seconds) = (total / 3600, (total % 3600) / 60, total % 60);
+
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("{}:{:02}", minutes, seconds)
+    }
+}
@@ -0,0 +1,290 @@
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+use crate::App;
+use crate::popup::{Confirm, InputPrompt, LatencyEditor, Picker, Popup, StreamField, StreamForm};
+
+pub fn draw_popup(f: &mut Frame, app: &App) {
+    match &app.popup {
+        Some(Popup::Input(prompt)) => draw_input_prompt(f, prompt),
+        Some(Popup::Picker(picker)) => draw_picker(f, picker),
+        Some(Popup::Latency(editor)) => draw_latency_editor(f, editor, app),
+        Some(Popup::StreamForm(form)) => draw_stream_form(f, form),
+        Some(Popup::Confirm(confirm)) => draw_confirm(f, confirm),
+        None => {}
+    }
+}
+
+fn draw_input_prompt(f: &mut Frame, prompt: &InputPrompt) {
+    let area = centered_rect(50, 5, f.size());
+
+    let block = Block::default()
+        .title(format!(" {} ", prompt.title))
+        .borders(Borders::ALL)
+        .style(Style::default().fg(Color::White));
+
+    let content = Paragraph::new(vec![
+        Line::from(vec![
+            Span::styled("> ", Style::default().fg(Color::Yellow)),
+            Span::styled(prompt.value.as_str(), Style::default().fg(Color::White).add_modifier(Modifier::BOLD)),
+            Span::styled("_", Style::default().fg(Color::Green)),
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Enter", Style::default().fg(Color::Yellow)),
+            Span::styled(" to confirm | ", Style::default().fg(Color::Magenta)),
+            Span::styled("Esc", Style::default().fg(Color::Yellow)),
+            Span::styled(" to cancel", Style::default().fg(Color::Magenta)),
+        ]),
+    ])
+    .alignment(Alignment::Left)
+    .block(block);
+
+    f.render_widget(Clear, area);
+    f.render_widget(content, area);
+}
+
+fn draw_picker(f: &mut Frame, picker: &Picker) {
+    // Borders and the hint line take four rows
+    let height = (picker.items.len() as u16).saturating_add(4);
+    let area = centered_rect(50, height, f.size());
+
+    let block = Block::default()
+        .title(format!(" {} ", picker.title))
+        .borders(Borders::ALL)
+        .style(Style::default().fg(Color::White));
+    let inner_area = block.inner(area);
+
+    let items: Vec<ListItem> = picker.items
+        .iter()
+        .enumerate()
+        .map(|(idx, item)| {
+            let is_selected = idx == picker.selected;
+            let prefix = if is_selected { "> " } else { "  " };
+
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{}{}", prefix, item.label),
+                    if is_selected {
+                        Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)
+                    } else {
+                        Style::default().fg(Color::White)
+                    }
+                ),
+                Span::styled(format!("  {}", item.detail), Style::default().fg(Color::Gray)),
+            ]))
+        })
+        .collect();
+
+    let mut list_state = ListState::default();
+    list_state.select(Some(picker.selected));
+
+    let list_area = Rect {
+        height: inner_area.height.saturating_sub(2),
+        ..inner_area
+    };
+    let hints_area = Rect {
+        y: inner_area.y + inner_area.height.saturating_sub(1),
+        height: 1,
+        ..inner_area
+    };
+
+    let hints = Paragraph::new(Line::from(vec![
+        Span::styled("Enter", Style::default().fg(Color::Yellow)),
+        Span::styled(" to select | ", Style::default().fg(Color::Magenta)),
+        Span::styled("Esc", Style::default().fg(Color::Yellow)),
+        Span::styled(" to cancel", Style::default().fg(Color::Magenta)),
+    ]));
+
+    f.render_widget(Clear, area);
+    f.render_widget(block, area);
+    f.render_stateful_widget(List::new(items), list_area, &mut list_state);
+    f.render_widget(hints, hints_area);
+}
+
+fn draw_latency_editor(f: &mut Frame, editor: &LatencyEditor, app: &App) {
+    // Members of the edited client's group, to compare latencies
+    let group_clients: Vec<_> = app.network.status
+        .iter()
+        .flat_map(|status| status.server.groups.iter())
+        .find(|group| group.clients.iter().any(|client| client.id == editor.client_id))
+        .map(|group| group.clients.iter().collect())
+        .unwrap_or_default();
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("Latency: ", Style::default().fg(Color::Yellow)),
+            Span::styled(
+                format!("{} ms", editor.current),
+                Style::default().fg(Color::Green).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(format!("  (was {} ms)", editor.original), Style::default().fg(Color::Gray)),
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Group clients:", Style::default().fg(Color::Yellow)),
+        ]),
+    ];
+
+    for client in &group_clients {
+        let is_edited = client.id == editor.client_id;
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("  {} {}: ", if is_edited { ">" } else { "-" }, client.display_name()),
+                Style::default().fg(Color::Blue),
+            ),
+            Span::styled(
+                format!("{} ms", client.config.latency),
+                if is_edited {
+                    Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(Color::White)
+                },
+            ),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled("←→", Style::default().fg(Color::Yellow)),
+        Span::styled(" ±1 ms | ", Style::default().fg(Color::Magenta)),
+        Span::styled("↑↓", Style::default().fg(Color::Yellow)),
+        Span::styled(" ±10 ms | ", Style::default().fg(Color::Magenta)),
+        Span::styled("r", Style::default().fg(Color::Yellow)),
+        Span::styled(" to revert", Style::default().fg(Color::Magenta)),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled("Enter", Style::default().fg(Color::Yellow)),
+        Span::styled(" to keep | ", Style::default().fg(Color::Magenta)),
+        Span::styled("Esc", Style::default().fg(Color::Yellow)),
+        Span::styled(" to revert and close", Style::default().fg(Color::Magenta)),
+    ]));
+
+    let area = centered_rect(50, lines.len() as u16 + 2, f.size());
+    let content = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .title(" Client latency ")
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::White)),
+        );
+
+    f.render_widget(Clear, area);
+    f.render_widget(content, area);
+}
+
+fn draw_stream_form(f: &mut Frame, form: &StreamForm) {
+    let mut lines = Vec::new();
+
+    for (idx, field) in StreamField::ALL.iter().enumerate() {
+        let is_focused = idx == form.focused;
+        let value = form.value(*field);
+
+        let label_style = if is_focused {
+            Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::Yellow)
+        };
+
+        let value_span = if *field == StreamField::Scheme {
+            let text = if is_focused { format!("< {} >", value) } else { value.to_string() };
+            Span::styled(text, Style::default().fg(Color::White).add_modifier(Modifier::BOLD))
+        } else if value.is_empty() && !is_focused {
+            Span::styled(field.placeholder(), Style::default().fg(Color::DarkGray))
+        } else {
+            Span::styled(value.to_string(), Style::default().fg(Color::White))
+        };
+
+        let mut spans = vec![
+            Span::styled(if is_focused { "> " } else { "  " }, label_style),
+            Span::styled(format!("{:<15}", format!("{}:", field.label())), label_style),
+            value_span,
+        ];
+        if is_focused && *field != StreamField::Scheme {
+            spans.push(Span::styled("_", Style::default().fg(Color::Green)));
+        }
+        lines.push(Line::from(spans));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(match &form.error {
+        Some(error) => Line::from(Span::styled(error.clone(), Style::default().fg(Color::Red))),
+        None => Line::from(Span::styled(
+            form.to_uri().unwrap_or_default(),
+            Style::default().fg(Color::Gray),
+        )),
+    });
+    lines.push(Line::from(vec![
+        Span::styled("Tab", Style::default().fg(Color::Yellow)),
+        Span::styled(" next field | ", Style::default().fg(Color::Magenta)),
+        Span::styled("←→", Style::default().fg(Color::Yellow)),
+        Span::styled(" scheme | ", Style::default().fg(Color::Magenta)),
+        Span::styled("Enter", Style::default().fg(Color::Yellow)),
+        Span::styled(" to add | ", Style::default().fg(Color::Magenta)),
+        Span::styled("Esc", Style::default().fg(Color::Yellow)),
+        Span::styled(" to cancel", Style::default().fg(Color::Magenta)),
+    ]));
+
+    let area = centered_rect(60, lines.len() as u16 + 2, f.size());
+    let content = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .title(" Add stream ")
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::White)),
+        );
+
+    f.render_widget(Clear, area);
+    f.render_widget(content, area);
+}
+
+fn draw_confirm(f: &mut Frame, confirm: &Confirm) {
+    let mut lines = vec![
+        Line::from(Span::styled(confirm.message.as_str(), Style::default().fg(Color::White))),
+        Line::from(""),
+    ];
+
+    for detail in &confirm.details {
+        lines.push(Line::from(Span::styled(detail.as_str(), Style::default().fg(Color::Gray))));
+    }
+    if !confirm.details.is_empty() {
+        lines.push(Line::from(""));
+    }
+
+    lines.push(Line::from(vec![
+        Span::styled("y", Style::default().fg(Color::Yellow)),
+        Span::styled(" to confirm | ", Style::default().fg(Color::Magenta)),
+        Span::styled("n", Style::default().fg(Color::Yellow)),
+        Span::styled(" to cancel", Style::default().fg(Color::Magenta)),
+    ]));
+
+    let area = centered_rect(50, lines.len() as u16 + 2, f.size());
+    let content = Paragraph::new(lines)
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .title(" Confirm ")
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::White)),
+        );
+
+    f.render_widget(Clear, area);
+    f.render_widget(content, area);
+}
+
+// Fixed height rectangle centered in `area`, `width` is a percentage
+fn centered_rect(width: u16, height: u16, area: Rect) -> Rect {
+    let width = area.width * width / 100;
+    let height = height.min(area.height);
+
+    Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    }
+}
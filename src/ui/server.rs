@@ -0,0 +1,167 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+use std::{collections::BTreeMap, time::Duration};
+use crate::App;
+
+pub fn draw_server(f: &mut Frame, area: Rect, app: &App) {
+    // Server on the left, what it serves on the right
+    let content_layout = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage(50),  // Host and snapserver
+            Constraint::Percentage(50),  // Totals and breakdowns
+        ])
+        .split(area);
+
+    let server_block = Block::default()
+        .title(" Server ")
+        .borders(Borders::ALL);
+    let totals_block = Block::default()
+        .title(" Totals ")
+        .borders(Borders::ALL);
+
+    let server_area = inner(content_layout[0]);
+    let totals_area = inner(content_layout[1]);
+    f.render_widget(server_block, content_layout[0]);
+    f.render_widget(totals_block, content_layout[1]);
+
+    let Some(status) = &app.network.status else {
+        let paragraph = Paragraph::new("No data available. Press 'r' to refresh.")
+            .style(Style::default().fg(Color::White))
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, server_area);
+        return;
+    };
+
+    let host = &status.server.server.host;
+    let snapserver = &status.server.server.snapserver;
+
+    let mut server = Vec::new();
+    server.push(heading("Host"));
+    server.push(field("Name: ", &host.name));
+    server.push(field("OS: ", &host.os));
+    server.push(field("Arch: ", &host.arch));
+    server.push(field("Ip: ", &host.ip));
+    server.push(field("Mac: ", &host.mac));
+    server.push(Line::default());
+
+    server.push(heading("Snapserver"));
+    server.push(field("Name: ", &snapserver.name));
+    server.push(field("Version: ", &snapserver.version));
+    server.push(field("Protocol Version: ", &snapserver.protocol_version.to_string()));
+    server.push(field(
+        "Control Protocol Version: ",
+        &snapserver.control_protocol_version.map(|version| version.to_string()).unwrap_or_else(|| "-".to_string()),
+    ));
+    server.push(Line::default());
+
+    // snapserver doesn't report its uptime, so count from when we saw it come up
+    server.push(heading("Session"));
+    server.push(field("Url: ", app.network.get_url()));
+    server.push(field(
+        "Up For: ",
+        &app.network.connected_since
+            .map(|since| format_duration(since.elapsed()))
+            .unwrap_or_else(|| "-".to_string()),
+    ));
+    server.push(field("Notifications: ", &app.network.notification_count.to_string()));
+    server.push(field(
+        "Last Notification: ",
+        &app.network.last_notification
+            .map(|at| format!("{} ago", format_duration(at.elapsed())))
+            .unwrap_or_else(|| "-".to_string()),
+    ));
+
+    f.render_widget(Paragraph::new(server), server_area);
+
+    let clients: Vec<_> = status.server.groups.iter()
+        .flat_map(|group| group.clients.iter())
+        .collect();
+    let connected = clients.iter().filter(|client| client.connected).count();
+
+    let mut totals = Vec::new();
+    totals.push(field("Streams: ", &status.server.streams.len().to_string()));
+    totals.push(field("Groups: ", &status.server.groups.len().to_string()));
+    totals.push(Line::from(vec![
+        Span::styled("Clients: ", Style::default().fg(Color::Yellow)),
+        Span::styled(clients.len().to_string(), Style::default().fg(Color::White)),
+        Span::styled(" (", Style::default().fg(Color::Gray)),
+        Span::styled(format!("{} connected", connected), Style::default().fg(Color::Green)),
+        Span::styled(", ", Style::default().fg(Color::Gray)),
+        Span::styled(format!("{} disconnected", clients.len() - connected), Style::default().fg(Color::Red)),
+        Span::styled(")", Style::default().fg(Color::Gray)),
+    ]));
+    totals.push(Line::default());
+
+    // Streams without the parameter use the server's default
+    let mut codecs = BTreeMap::new();
+    let mut sample_formats = BTreeMap::new();
+    for stream in &status.server.streams {
+        let query = &stream.uri.query;
+        *codecs.entry(query.codec.as_deref().unwrap_or("(default)")).or_insert(0) += 1;
+        *sample_formats.entry(query.sample_format.as_deref().unwrap_or("(default)")).or_insert(0) += 1;
+    }
+
+    totals.push(heading("Streams by Codec"));
+    totals.extend(breakdown(&codecs));
+    totals.push(Line::default());
+    totals.push(heading("Streams by Sample Format"));
+    totals.extend(breakdown(&sample_formats));
+
+    f.render_widget(Paragraph::new(totals), totals_area);
+}
+
+// Inside of a panel, with the same margins as the details panels
+fn inner(area: Rect) -> Rect {
+    Rect {
+        x: area.x + 4,
+        y: area.y + 2,
+        width: area.width.saturating_sub(5),
+        height: area.height.saturating_sub(3),
+    }
+}
+
+fn heading(title: &str) -> Line<'static> {
+    Line::from(Span::styled(title.to_string(), Style::default().fg(Color::Blue)))
+}
+
+fn field(label: &str, value: &str) -> Line<'static> {
+    Line::from(vec![
+        Span::styled(label.to_string(), Style::default().fg(Color::Yellow)),
+        Span::styled(value.to_string(), Style::default().fg(Color::White)),
+    ])
+}
+
+fn breakdown(counts: &BTreeMap<&str, usize>) -> Vec<Line<'static>> {
+    if counts.is_empty() {
+        return vec![Line::from(Span::styled("  No streams", Style::default().fg(Color::Gray)))];
+    }
+
+    counts.iter()
+        .map(|(name, count)| {
+            Line::from(vec![
+                Span::styled(format!("  {}: ", name), Style::default().fg(Color::Yellow)),
+                Span::styled(count.to_string(), Style::default().fg(Color::White)),
+            ])
+        })
+        .collect()
+}
+
+// Like 2d 3h 4m, 3h 4m 5s or 4m 5s
+fn format_duration(duration: Duration) -> String {
+    let total = duration.as_secs();
+    let (days, hours, minutes, seconds) = (total / 86400, (total % 86400) / 3600, (total % 3600) / 60, total % 60);
+
+    if days > 0 {
+        format!("{}d {}h {}m", days, hours, minutes)
+    } else if hours > 0 {
+        format!("{}h {}m {}s", hours, minutes, seconds)
+    } else {
+        format!("{}m {}s", minutes, seconds)
+    }
+}
@@ -6,7 +6,11 @@ use ratatui::{
     Frame,
 };
 use crate::App;
+use crate::snapcast::StreamCommand;
+use crate::ui::{key_hints, key_hints_enabled};
 
+// Built line by line, one push per field
+#[allow(clippy::vec_init_then_push)]
 pub fn draw_stream_details(f: &mut Frame, area: Rect, app: &App) {
     // Create a block with a title for the stream details
     let block = Block::default()
@@ -16,117 +20,155 @@ pub fn draw_stream_details(f: &mut Frame, area: Rect, app: &App) {
     // Render the block
     f.render_widget(block, area);
 
-    // Create an inner area with margins
+    // Create an inner area with margins, keeping the last three rows for key hints
     let inner_area = Rect {
         x: area.x + 4,
         y: area.y + 2,
-        width: area.width.saturating_sub(2),
-        height: area.height.saturating_sub(2),
+        width: area.width.saturating_sub(5),
+        height: area.height.saturating_sub(6),
     };
 
     // Check if we have data and a selected item
-    if let Some(status) = &app.snapcast_client.status {
-        if let Some(selected_idx) = app.selected_item {
-            if let Some(stream) = status.server.streams.get(selected_idx) {
-                let mut details = Vec::new();
-
-                details.push(Line::from(vec![
-                    Span::styled("Id: ", Style::default().fg(Color::Yellow)),
-                    Span::styled(stream.id.to_string(), Style::default().fg(Color::White)),
-                ]));
-
-                details.push(Line::from(vec![
-                    Span::styled("Name: ", Style::default().fg(Color::Yellow)),
-                    Span::styled(stream.uri.query.name.to_string(), Style::default().fg(Color::White)),
-                ]));
-
-                details.push(Line::from(vec![
-                    Span::styled("Status: ", Style::default().fg(Color::Yellow)),
-                    Span::styled(stream.status.to_string(), Style::default().fg(Color::White)),
-                ]));
-
-                details.push(Line::from(vec![
-                    Span::styled("Host: ", Style::default().fg(Color::Yellow)),
-                    Span::styled(stream.uri.host.to_string(), Style::default().fg(Color::White)),
-                ]));
-
-                details.push(Line::from(vec![
-                    Span::styled("Scheme: ", Style::default().fg(Color::Yellow)),
-                    Span::styled(stream.uri.scheme.to_string(), Style::default().fg(Color::White)),
-                ]));
-
-                details.push(Line::from(vec![
-                    Span::styled("Path: ", Style::default().fg(Color::Yellow)),
-                    Span::styled(stream.uri.path.to_string(), Style::default().fg(Color::White)),
-                ]));
-
-                details.push(Line::from(vec![
-                    Span::styled("Mode: ", Style::default().fg(Color::Yellow)),
-                    Span::styled(stream.uri.query.mode.clone().expect("REASON").to_string(), Style::default().fg(Color::White)),
-                ]));
-
-                details.push(Line::from(vec![
-                    Span::styled("Codec: ", Style::default().fg(Color::Yellow)),
-                    Span::styled(stream.uri.query.codec.clone().expect("REASON").to_string(), Style::default().fg(Color::White)),
-                ]));
-
-                details.push(Line::from(vec![
-                    Span::styled("Sample Format: ", Style::default().fg(Color::Yellow)),
-                    Span::styled(stream.uri.query.sample_format.clone().expect("REASON").to_string(), Style::default().fg(Color::White)),
-                ]));
-
-                details.push(Line::from(vec![
-                    Span::styled("Chunk Ms: ", Style::default().fg(Color::Yellow)),
-                    Span::styled(stream.uri.query.chunk_ms.clone().expect("REASON").to_string(), Style::default().fg(Color::White)),
-                ]));
-
-                details.push(Line::from(vec![
-                    Span::styled("Fragment: ", Style::default().fg(Color::Yellow)),
-                    Span::styled(stream.uri.fragment.to_string(), Style::default().fg(Color::White)),
-                ]));
-
-                details.push(Line::from(vec![
-                    Span::styled("Raw: ", Style::default().fg(Color::Yellow)),
-                    Span::styled(stream.uri.raw.to_string(), Style::default().fg(Color::White)),
-                ]));
-
-                details.push(Line::from(vec![
-                    Span::styled("Can Control: ", Style::default().fg(Color::Yellow)),
-                    Span::styled(stream.properties.can_control.to_string(), Style::default().fg(Color::White)),
-                ]));
-
-                details.push(Line::from(vec![
-                    Span::styled("Can Play: ", Style::default().fg(Color::Yellow)),
-                    Span::styled(stream.properties.can_play.to_string(), Style::default().fg(Color::White)),
-                ]));
-
-                details.push(Line::from(vec![
-                    Span::styled("Can Pause: ", Style::default().fg(Color::Yellow)),
-                    Span::styled(stream.properties.can_pause.to_string(), Style::default().fg(Color::White)),
-                ]));
-
-                details.push(Line::from(vec![
-                    Span::styled("Can Seek: ", Style::default().fg(Color::Yellow)),
-                    Span::styled(stream.properties.can_seek.to_string(), Style::default().fg(Color::White)),
-                ]));
-
-                details.push(Line::from(vec![
-                    Span::styled("Can Go Next: ", Style::default().fg(Color::Yellow)),
-                    Span::styled(stream.properties.can_go_next.to_string(), Style::default().fg(Color::White)),
-                ]));
+    if app.network.status.is_some() {
+        if let Some(stream) = app.selected_stream() {
+            let mut details = Vec::new();
+
+            details.push(Line::from(vec![
+                Span::styled("Id: ", Style::default().fg(Color::Yellow)),
+                Span::styled(stream.id.to_string(), Style::default().fg(Color::White)),
+            ]));
+
+            details.push(Line::from(vec![
+                Span::styled("Name: ", Style::default().fg(Color::Yellow)),
+                Span::styled(stream.display_name().to_string(), Style::default().fg(Color::White)),
+            ]));
+
+            details.push(Line::from(vec![
+                Span::styled("Status: ", Style::default().fg(Color::Yellow)),
+                Span::styled(stream.status.to_string(), Style::default().fg(Color::White)),
+            ]));
+
+            details.push(Line::from(vec![
+                Span::styled("Host: ", Style::default().fg(Color::Yellow)),
+                Span::styled(stream.uri.host.to_string(), Style::default().fg(Color::White)),
+            ]));
+
+            details.push(Line::from(vec![
+                Span::styled("Scheme: ", Style::default().fg(Color::Yellow)),
+                Span::styled(stream.uri.scheme.to_string(), Style::default().fg(Color::White)),
+            ]));
+
+            details.push(Line::from(vec![
+                Span::styled("Path: ", Style::default().fg(Color::Yellow)),
+                Span::styled(stream.uri.path.to_string(), Style::default().fg(Color::White)),
+            ]));
+
+            // Query parameters depend on the stream type, show whichever the server sent
+            let query = &stream.uri.query;
+            let known = [
+                ("Mode: ", &query.mode),
+                ("Codec: ", &query.codec),
+                ("Sample Format: ", &query.sample_format),
+                ("Chunk Ms: ", &query.chunk_ms),
+            ];
+            for (label, value) in known {
+                if let Some(value) = value {
+                    details.push(Line::from(vec![
+                        Span::styled(label, Style::default().fg(Color::Yellow)),
+                        Span::styled(value.to_string(), Style::default().fg(Color::White)),
+                    ]));
+                }
+            }
 
+            for (key, value) in &query.extra {
+                let value = match value.as_str() {
+                    Some(value) => value.to_string(),
+                    None => value.to_string(),
+                };
                 details.push(Line::from(vec![
-                    Span::styled("Can Go Previous: ", Style::default().fg(Color::Yellow)),
-                    Span::styled(stream.properties.can_go_previous.to_string(), Style::default().fg(Color::White)),
+                    Span::styled(format!("{}: ", key), Style::default().fg(Color::Yellow)),
+                    Span::styled(value, Style::default().fg(Color::White)),
                 ]));
-
-                // Create the paragraph with all the details
-                let details = Paragraph::new(details);
-
-                // Render the details
-                f.render_widget(details, inner_area);
-                return;
             }
+
+            details.push(Line::from(vec![
+                Span::styled("Fragment: ", Style::default().fg(Color::Yellow)),
+                Span::styled(stream.uri.fragment.to_string(), Style::default().fg(Color::White)),
+            ]));
+
+            details.push(Line::from(vec![
+                Span::styled("Raw: ", Style::default().fg(Color::Yellow)),
+                Span::styled(stream.uri.raw.to_string(), Style::default().fg(Color::White)),
+            ]));
+
+            details.push(Line::from(vec![
+                Span::styled("Can Control: ", Style::default().fg(Color::Yellow)),
+                Span::styled(stream.properties.can_control.to_string(), Style::default().fg(Color::White)),
+            ]));
+
+            details.push(Line::from(vec![
+                Span::styled("Can Play: ", Style::default().fg(Color::Yellow)),
+                Span::styled(stream.properties.can_play.to_string(), Style::default().fg(Color::White)),
+            ]));
+
+            details.push(Line::from(vec![
+                Span::styled("Can Pause: ", Style::default().fg(Color::Yellow)),
+                Span::styled(stream.properties.can_pause.to_string(), Style::default().fg(Color::White)),
+            ]));
+
+            details.push(Line::from(vec![
+                Span::styled("Can Seek: ", Style::default().fg(Color::Yellow)),
+                Span::styled(stream.properties.can_seek.to_string(), Style::default().fg(Color::White)),
+            ]));
+
+            details.push(Line::from(vec![
+                Span::styled("Can Go Next: ", Style::default().fg(Color::Yellow)),
+                Span::styled(stream.properties.can_go_next.to_string(), Style::default().fg(Color::White)),
+            ]));
+
+            details.push(Line::from(vec![
+                Span::styled("Can Go Previous: ", Style::default().fg(Color::Yellow)),
+                Span::styled(stream.properties.can_go_previous.to_string(), Style::default().fg(Color::White)),
+            ]));
+
+            // Create the paragraph with all the details
+            let details = Paragraph::new(details);
+
+            // Render the details
+            f.render_widget(details, inner_area);
+
+            // Transport and property keys on the last lines, greyed out when the stream can't do them
+            let hints_area = Rect {
+                x: inner_area.x,
+                y: area.y + area.height.saturating_sub(4),
+                width: inner_area.width,
+                height: 3,
+            };
+            let supports = |command: StreamCommand| command.is_supported(&stream.properties);
+            let can_control = stream.properties.can_control;
+            let hints = Paragraph::new(vec![
+                key_hints_enabled(&[
+                    ("space", "play/pause", supports(StreamCommand::PlayPause)),
+                    ("p", "play", supports(StreamCommand::Play)),
+                    ("P", "pause", supports(StreamCommand::Pause)),
+                    ("<", "previous", supports(StreamCommand::Previous)),
+                    (">", "next", supports(StreamCommand::Next)),
+                    ("[/]", "seek", supports(StreamCommand::Seek(0.0))),
+                ]),
+                key_hints_enabled(&[
+                    ("l", "loop", can_control),
+                    ("h", "shuffle", can_control),
+                    ("+/-", "volume", can_control && stream.properties.volume.is_some()),
+                    ("m", "mute", can_control),
+                    ("{/}", "rate", can_control),
+                ]),
+                key_hints(&[
+                    ("a", "add stream"),
+                    ("x", "remove stream"),
+                ]),
+            ]);
+            f.render_widget(hints, hints_area);
+            return;
         }
         // If we have data but nothing is selected, show an empty block
         let empty_block = Block::default().borders(Borders::NONE);
@@ -17,7 +17,7 @@ pub fn draw_streams_list(f: &mut Frame, area: Rect, app: &App) {
     };
 
     // Calculate streams count for title
-    let streams_count = if let Some(status) = &app.snapcast_client.status {
+    let streams_count = if let Some(status) = &app.network.status {
         status.server.streams.len()
     } else {
         0
@@ -37,16 +37,19 @@ pub fn draw_streams_list(f: &mut Frame, area: Rect, app: &App) {
         .alignment(Alignment::Center)
         .block(Block::default().borders(Borders::NONE));
 
-    if let Some(status) = &app.snapcast_client.status {
-        let mut list_state = ListState::default();
-        list_state.select(app.selected_item);
+    if let Some(status) = &app.network.status {
+        let selected = app.selected_index();
+        // Start from where this tab's list was scrolled to last time
+        let mut list_state = ListState::default()
+            .with_offset(app.selection.offset(2))
+            .with_selected(selected);
 
         let items: Vec<ListItem> = status.server.streams
             .iter()
             .enumerate()
             .map(|(idx, stream)| {
-                let name = &stream.uri.query.name;
-                let prefix = if Some(idx) == app.selected_item {
+                let name = stream.display_name();
+                let prefix = if Some(idx) == selected {
                     "> "  // Selection indicator
                 } else {
                     "  "  // Regular indentation
@@ -55,7 +58,7 @@ pub fn draw_streams_list(f: &mut Frame, area: Rect, app: &App) {
                 let content = Line::from(vec![
                     Span::styled(
                         format!("{}{}", prefix, name),
-                        if Some(idx) == app.selected_item {
+                        if Some(idx) == selected {
                             Style::default()
                                 .fg(Color::Green)
                                 .add_modifier(Modifier::BOLD)
@@ -75,6 +78,7 @@ pub fn draw_streams_list(f: &mut Frame, area: Rect, app: &App) {
         // Render the centered title above the list
         f.render_widget(title_paragraph, list_area);
         f.render_stateful_widget(list, inner_list_area, &mut list_state);
+        app.selection.set_offset(2, list_state.offset());
     } else {
         let content = Paragraph::new("No data available. Press 'r' to refresh.")
             .style(Style::default().fg(Color::White))
@@ -3,6 +3,7 @@ mod details;
 
 use ratatui::Frame;
 use crate::App;
+use crate::ui::draw_now_playing;
 
 pub use list::draw_streams_list;
 pub use details::draw_stream_details;
@@ -20,6 +21,18 @@ pub fn draw_streams(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
     // Draw the streams list
     draw_streams_list(f, content_layout[0], app);
 
+    // Split the right side between now playing and the stream details
+    let details_layout = ratatui::layout::Layout::default()
+        .direction(ratatui::layout::Direction::Vertical)
+        .constraints([
+            ratatui::layout::Constraint::Length(6),  // Now playing
+            ratatui::layout::Constraint::Min(1),     // Stream details
+        ])
+        .split(content_layout[1]);
+
+    // Draw what the selected stream is playing
+    draw_now_playing(f, details_layout[0], app.selected_stream());
+
     // Draw the stream details
-    draw_stream_details(f, content_layout[1], app);
+    draw_stream_details(f, details_layout[1], app);
 }
\ No newline at end of file
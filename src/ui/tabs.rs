@@ -8,15 +8,23 @@ use ratatui::{
 use crate::App;
 
 pub fn draw_tabs(f: &mut Frame, area: Rect, app: &App) {
-    let tabs = vec!["Groups", "Clients", "Streams"];
+    // Tab titles with the position of their hotkey letter
+    let tabs = [("Groups", 0), ("Clients", 0), ("Streams", 0), ("Server", 1), ("Dashboard", 0)];
 
-    let titles = tabs.iter().enumerate().map(|(i, t)| {
-        let (first, rest) = t.split_at(1);
+    let titles = tabs.iter().enumerate().map(|(i, (t, hotkey))| {
+        let (before, rest) = t.split_at(*hotkey);
+        let (key, rest) = rest.split_at(1);
         let is_selected = i == app.current_tab;
 
         Line::from(vec![
             Span::styled(
-                first,
+                before,
+                Style::default()
+                    .fg(if is_selected { Color::Green } else { Color::White })
+                    .add_modifier(if is_selected { Modifier::BOLD } else { Modifier::empty() })
+            ),
+            Span::styled(
+                key,
                 Style::default()
                     .fg(if is_selected { Color::Green } else { Color::Yellow })
                     .add_modifier(Modifier::UNDERLINED)